@@ -6,6 +6,17 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     pub const STARRY: &str = include_str!("../sudokus/starry.txt");
     pub const TURBINE: &str = include_str!("../sudokus/turbine.txt");
 
+    // The "sudokus/starry.txt" board with the center 4 removed, giving it 21 solutions
+    pub const STARRY_MULTIPLE_SOLUTIONS: &str = "6-------4
+                                                 -42-3-51-
+                                                 -85---32-
+                                                 ---3-5---
+                                                 53-----68
+                                                 ---6-2---
+                                                 -26-5-89-
+                                                 -97---45-
+                                                 1-------2";
+
     c.bench_function("solve one-eighty (easy)", |b| {
         b.iter_batched(
             || Board::try_from(ONEEIGHTY).unwrap(),
@@ -29,6 +40,43 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             BatchSize::SmallInput,
         )
     });
+
+    c.bench_function("solve fast starry (medium)", |b| {
+        b.iter_batched(
+            || Board::try_from(STARRY).unwrap(),
+            |board| board.solve_fast().unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    c.bench_function("solve fast turbine (hard)", |b| {
+        b.iter_batched(
+            || Board::try_from(TURBINE).unwrap(),
+            |board| board.solve_fast().unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    c.bench_function("count solutions starry multiple solutions", |b| {
+        b.iter_batched(
+            || Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap(),
+            |board| board.count_solutions(None, None),
+            BatchSize::SmallInput,
+        )
+    });
+
+    c.bench_function("filter solve_iter starry multiple solutions", |b| {
+        b.iter_batched(
+            || Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap(),
+            |board| {
+                board
+                    .solve_iter()
+                    .filter(|(_, is_solved)| *is_solved)
+                    .count()
+            },
+            BatchSize::SmallInput,
+        )
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -3,8 +3,11 @@
 mod renderers;
 
 use clap::{crate_version, Arg, Command};
-use fabrik::Board;
-use renderers::{DelayedRenderer, Renderer, ResultOnlyRenderer, SudokuRenderer};
+use fabrik::{Board, SudokuParseError};
+use renderers::{
+    DelayedRenderer, FileSequenceRenderer, HighlightRenderer, Renderer, ResultOnlyRenderer,
+    SudokuRenderer,
+};
 use std::{fs, time::Duration};
 
 fn main() {
@@ -25,6 +28,19 @@ fn main() {
                 .takes_value(true)
                 .help("Set the delay in ms used in display mode (defaults to 50ms)"),
         )
+        .arg(
+            Arg::new("highlight")
+                .long("highlight")
+                .short('c')
+                .help("Color the given clues and any fields that fail to parse"),
+        )
+        .arg(
+            Arg::new("frames")
+                .long("frames")
+                .takes_value(true)
+                .value_name("DIR")
+                .help("Write every step of the solution to numbered files in DIR"),
+        )
         .arg(
             Arg::new("INPUT")
                 .help("Sets the input file to use")
@@ -43,6 +59,10 @@ fn main() {
         let delay = Duration::from_millis(delay);
 
         Renderer::Delayed(DelayedRenderer { delay })
+    } else if let Some(directory) = matches.value_of("frames") {
+        Renderer::FileSequence(FileSequenceRenderer::new(directory))
+    } else if matches.is_present("highlight") {
+        Renderer::Highlight(HighlightRenderer::new())
     } else {
         Renderer::FinalResultOnly(ResultOnlyRenderer {})
     };
@@ -70,17 +90,23 @@ fn solve<T: SudokuRenderer>(
     renderer: &T,
 ) -> Result<Board, Box<dyn std::error::Error>> {
     let sudoku_file = fs::read_to_string(filename)?;
-    let board = Board::try_from(sudoku_file)?;
-    let mut solved_board = board;
 
-    for (board_snapshot, is_solved) in board.solve_iter() {
+    // Show where the parse errors are on the grid before bailing
+    let board = Board::try_from(sudoku_file.as_str()).inspect_err(|error| {
+        if let SudokuParseError::ParseErrors(_) = error {
+            renderer.display_parse_error(&sudoku_file, error);
+        }
+    })?;
+
+    let mut iterator = board.solve_iter();
+
+    for (board_snapshot, is_solved) in iterator.by_ref() {
         renderer.display_step(&board_snapshot);
 
         if is_solved {
-            solved_board = board_snapshot;
             break;
         }
     }
 
-    Ok(solved_board)
+    Ok(iterator.into_board())
 }
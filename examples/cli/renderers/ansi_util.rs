@@ -14,3 +14,11 @@ pub fn hide_cursor() {
 pub fn show_cursor() {
     print!("{}[?25h", 27 as char);
 }
+
+pub fn red(text: &str) -> String {
+    format!("{}[31m{}{}[0m", 27 as char, text, 27 as char)
+}
+
+pub fn green(text: &str) -> String {
+    format!("{}[32m{}{}[0m", 27 as char, text, 27 as char)
+}
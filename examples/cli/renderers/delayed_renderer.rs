@@ -1,4 +1,4 @@
-use fabrik::Board;
+use fabrik::{Board, SudokuParseError};
 
 use crate::renderers::ansi_util::*;
 use crate::renderers::SudokuRenderer;
@@ -28,6 +28,11 @@ impl SudokuRenderer for DelayedRenderer {
     // we will not display the final result
     fn display_final_result(&self, _board: &Board) {}
 
+    fn display_parse_error(&self, input: &str, error: &SudokuParseError) {
+        cursor_at_position(3, 1);
+        print!("{}", error.render_on(input));
+    }
+
     fn teardown(&self) {
         show_cursor();
     }
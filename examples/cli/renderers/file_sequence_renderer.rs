@@ -0,0 +1,58 @@
+use fabrik::{Board, SudokuParseError};
+
+use crate::renderers::SudokuRenderer;
+
+use std::{cell::Cell, fs, path::PathBuf};
+
+// Writes every step of the solution to a numbered file in a directory, for example
+// frames/0000.txt, frames/0001.txt and so on. The frames can be assembled into an
+// animation using external tools.
+pub struct FileSequenceRenderer {
+    pub directory: PathBuf,
+    pub frame_count: Cell<usize>,
+}
+
+impl FileSequenceRenderer {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        FileSequenceRenderer {
+            directory: directory.into(),
+            frame_count: Cell::new(0),
+        }
+    }
+}
+
+impl SudokuRenderer for FileSequenceRenderer {
+    fn setup(&self, filename: &str) {
+        println!("{filename}");
+
+        if let Err(error) = fs::create_dir_all(&self.directory) {
+            println!("Could not create {}: {error}", self.directory.display());
+        }
+    }
+
+    fn display_step(&self, board: &Board) {
+        let frame = self.frame_count.get();
+        let path = self.directory.join(format!("{frame:04}.txt"));
+
+        match fs::write(&path, board.to_string()) {
+            Ok(()) => self.frame_count.set(frame + 1),
+            Err(error) => println!("Could not write {}: {error}", path.display()),
+        }
+    }
+
+    fn display_final_result(&self, board: &Board) {
+        print!("{board}");
+    }
+
+    fn display_parse_error(&self, input: &str, error: &SudokuParseError) {
+        print!("{}", error.render_on(input));
+    }
+
+    fn teardown(&self) {
+        println!(
+            "Wrote {} frames to {}",
+            self.frame_count.get(),
+            self.directory.display()
+        );
+    }
+}
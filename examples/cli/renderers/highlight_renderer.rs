@@ -0,0 +1,80 @@
+use fabrik::{Board, PositionIter, SudokuParseError};
+
+use crate::renderers::ansi_util::*;
+use crate::renderers::SudokuRenderer;
+
+use std::io::IsTerminal;
+
+// Renders the given clues of a solution in green, and fields that fail to parse in red.
+// Colors are only used when writing to a terminal.
+pub struct HighlightRenderer {
+    pub use_colors: bool,
+}
+
+impl HighlightRenderer {
+    pub fn new() -> Self {
+        HighlightRenderer {
+            use_colors: std::io::stdout().is_terminal(),
+        }
+    }
+
+    fn colored(&self, text: &str, color: fn(&str) -> String) -> String {
+        if self.use_colors {
+            color(text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+impl SudokuRenderer for HighlightRenderer {
+    fn setup(&self, filename: &str) {
+        println!("{filename}");
+    }
+
+    fn display_step(&self, _board: &Board) {}
+
+    fn display_final_result(&self, board: &Board) {
+        // Render the grid like the Display of a Board, coloring the givens
+        let mut output = String::from("+-----------+\n");
+
+        for (index, position) in PositionIter::from_first_field().enumerate() {
+            let (row, column) = (index / 9, index % 9);
+            let character = board.get_field(position).to_char().to_string();
+
+            if column == 0 {
+                output.push('|');
+            }
+
+            if board.is_given(position) {
+                output.push_str(&self.colored(&character, green));
+            } else {
+                output.push_str(&character);
+            }
+
+            if column % 3 == 2 {
+                output.push('|');
+            }
+
+            if column == 8 {
+                output.push('\n');
+
+                if row % 3 == 2 && row != 8 {
+                    output.push_str("+---+---+---+\n");
+                }
+            }
+        }
+
+        output.push_str("+-----------+\n");
+        print!("{output}");
+    }
+
+    fn display_parse_error(&self, input: &str, error: &SudokuParseError) {
+        print!(
+            "{}",
+            error.render_on(input).replace('X', &self.colored("X", red))
+        );
+    }
+
+    fn teardown(&self) {}
+}
@@ -1,10 +1,14 @@
 mod ansi_util;
 mod delayed_renderer;
+mod file_sequence_renderer;
+mod highlight_renderer;
 mod renderer;
 mod result_only_renderer;
 mod sudoku_renderer;
 
 pub use delayed_renderer::DelayedRenderer;
+pub use file_sequence_renderer::FileSequenceRenderer;
+pub use highlight_renderer::HighlightRenderer;
 pub use renderer::Renderer;
 pub use result_only_renderer::ResultOnlyRenderer;
 pub use sudoku_renderer::SudokuRenderer;
@@ -2,14 +2,19 @@
 //// functions. It contains the options for rendering sudokus in the example. It implements
 //// SudokuRenderer so it can be passed into a function with those trait bounds, and it just
 //// delegates to the internal renderers.
+#![allow(clippy::four_forward_slashes)]
+
+use fabrik::SudokuParseError;
 
 use crate::{renderers::SudokuRenderer, Board};
 
-use super::{DelayedRenderer, ResultOnlyRenderer};
+use super::{DelayedRenderer, FileSequenceRenderer, HighlightRenderer, ResultOnlyRenderer};
 
 pub enum Renderer {
     Delayed(DelayedRenderer),
     FinalResultOnly(ResultOnlyRenderer),
+    Highlight(HighlightRenderer),
+    FileSequence(FileSequenceRenderer),
 }
 
 impl SudokuRenderer for Renderer {
@@ -17,6 +22,8 @@ impl SudokuRenderer for Renderer {
         match self {
             Renderer::Delayed(renderer) => renderer.setup(filename),
             Renderer::FinalResultOnly(renderer) => renderer.setup(filename),
+            Renderer::Highlight(renderer) => renderer.setup(filename),
+            Renderer::FileSequence(renderer) => renderer.setup(filename),
         }
     }
 
@@ -24,6 +31,8 @@ impl SudokuRenderer for Renderer {
         match self {
             Renderer::Delayed(renderer) => renderer.display_step(board),
             Renderer::FinalResultOnly(renderer) => renderer.display_step(board),
+            Renderer::Highlight(renderer) => renderer.display_step(board),
+            Renderer::FileSequence(renderer) => renderer.display_step(board),
         }
     }
 
@@ -31,6 +40,17 @@ impl SudokuRenderer for Renderer {
         match self {
             Renderer::Delayed(renderer) => renderer.display_final_result(board),
             Renderer::FinalResultOnly(renderer) => renderer.display_final_result(board),
+            Renderer::Highlight(renderer) => renderer.display_final_result(board),
+            Renderer::FileSequence(renderer) => renderer.display_final_result(board),
+        }
+    }
+
+    fn display_parse_error(&self, input: &str, error: &SudokuParseError) {
+        match self {
+            Renderer::Delayed(renderer) => renderer.display_parse_error(input, error),
+            Renderer::FinalResultOnly(renderer) => renderer.display_parse_error(input, error),
+            Renderer::Highlight(renderer) => renderer.display_parse_error(input, error),
+            Renderer::FileSequence(renderer) => renderer.display_parse_error(input, error),
         }
     }
 
@@ -38,6 +58,8 @@ impl SudokuRenderer for Renderer {
         match self {
             Renderer::Delayed(renderer) => renderer.teardown(),
             Renderer::FinalResultOnly(renderer) => renderer.teardown(),
+            Renderer::Highlight(renderer) => renderer.teardown(),
+            Renderer::FileSequence(renderer) => renderer.teardown(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use fabrik::Board;
+use fabrik::{Board, SudokuParseError};
 
 use crate::renderers::SudokuRenderer;
 
@@ -15,5 +15,9 @@ impl SudokuRenderer for ResultOnlyRenderer {
         print!("{board}");
     }
 
+    fn display_parse_error(&self, input: &str, error: &SudokuParseError) {
+        print!("{}", error.render_on(input));
+    }
+
     fn teardown(&self) {}
 }
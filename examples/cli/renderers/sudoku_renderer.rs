@@ -1,8 +1,9 @@
-use fabrik::Board;
+use fabrik::{Board, SudokuParseError};
 
 pub trait SudokuRenderer {
     fn setup(&self, filename: &str);
     fn display_step(&self, board: &Board);
     fn display_final_result(&self, board: &Board);
+    fn display_parse_error(&self, input: &str, error: &SudokuParseError);
     fn teardown(&self);
 }
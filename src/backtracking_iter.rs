@@ -1,6 +1,12 @@
+use std::{iter::FusedIterator, sync::OnceLock};
+
 use crate::Board;
 
-use super::{field::Field, position::Position};
+use super::{
+    position::Position,
+    solve_stats::StatsIter,
+    solver_state::{SolverState, StepEvent},
+};
 
 /// Iterator emitting `Board`s representing each steps towards a solved
 /// sudoku using a backtracking algorithm.
@@ -8,95 +14,264 @@ use super::{field::Field, position::Position};
 /// The iterator will emit all possible boards for the input until it finally
 /// emits None, which means all possibilities have been tried for the given
 /// input board.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct BacktrackingIter {
-    board: Board,
-    current_position: Position,
-    stack: Vec<WorkOnField>,
-}
-
-enum WhatHappened {
-    PutNewFieldOnBoard,
-    RanOutOfStack,
+    state: SolverState,
+    initial_state: SolverState,
+    /// The number of boards emitted since the start of the search
+    emitted: usize,
+    /// The number of boards emitted from the start of the search up to and including the
+    /// first solution, or `None` if there is no solution. Found on demand by `size_hint`.
+    boards_to_first_solution: OnceLock<Option<usize>>,
 }
 
-#[derive(Copy, Clone, Debug)]
-struct WorkOnField(Position, u8);
-
 impl BacktrackingIter {
     /// Create a backtracking iterator for a Board
     pub fn new(board: Board) -> Self {
+        BacktrackingIter::starting_at(board, Position { row: 0, column: 0 })
+    }
+
+    /// Create a backtracking iterator for a Board starting at a `Position`
+    pub(crate) fn starting_at(board: Board, start: Position) -> Self {
+        BacktrackingIter::from_state(SolverState::starting_at(board, start))
+    }
+
+    /// Create a backtracking iterator emitting the boards of a `SolverState`
+    pub(crate) fn from_state(state: SolverState) -> Self {
         BacktrackingIter {
-            current_position: Position { row: 0, column: 0 },
-            board,
-            stack: Vec::new(),
+            initial_state: state.clone(),
+            state,
+            emitted: 0,
+            boards_to_first_solution: OnceLock::new(),
         }
     }
 
-    // Prepare instructions in the stack for execution
-    fn prepare_stack(&mut self, next_empty_field: Position) {
-        // Try the value 1 first. This will be incremented up until 9 during execution.
-        // We could have pushed 9 separate instructions instead, but this performs better.
-        self.stack.push(WorkOnField(next_empty_field, 1));
+    /// Get the depth of the search tree after the latest emitted board, which is the
+    /// number of fields currently filled in by the solver
+    pub fn depth(&self) -> usize {
+        self.state.depth()
+    }
+
+    /// Get the board of the latest emitted step
+    pub fn board(&self) -> &Board {
+        self.state.board()
     }
 
-    // Manipulate the board from the stack instructions
-    fn execute_stack(&mut self) -> WhatHappened {
+    /// Consume the iterator and get the board of the latest emitted step, which is the
+    /// solution when stopping at a solved board
+    pub fn into_board(self) -> Board {
+        *self.state.board()
+    }
+
+    /// Emit the `SolveStats` of the search together with every board. The stats are
+    /// counted from the current state of the iterator.
+    pub fn with_stats(self) -> StatsIter {
+        StatsIter::new(self.state)
+    }
+
+    /// Reset the iterator to the state it had before the first step, so the search
+    /// can be run again for the original board
+    pub fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+        self.emitted = 0;
+    }
+}
+
+impl Iterator for BacktrackingIter {
+    type Item = (Board, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Fields cleared while backtracking are not emitted, so keep stepping
+        // until a new field is put on the board.
         loop {
-            match self.stack.pop() {
-                Some(instruction) => match instruction {
-                    WorkOnField(pos, v) => {
-                        self.current_position = pos;
-
-                        for value in v..=10 {
-                            if value <= 9 {
-                                let field = Field::from_u8(value);
-
-                                if self.board.valid_number_at_position(pos, &field) {
-                                    // Insert WorkOnField(current_position, v + 1) on the top of the stack,
-                                    // to be able to resume work on this field if we backtrack to this position again.
-                                    self.stack.push(WorkOnField(pos, value + 1));
-
-                                    self.board.put_field(pos, field);
-                                    return WhatHappened::PutNewFieldOnBoard;
-                                }
-
-                                // If nothing is returned, we will simply run the for-loop again.
-                            } else {
-                                // We have tried all number 1..9 for this field. Clear it and loop in the outer loop,
-                                // effectively backtracking to the previous position.
-                                self.board.put_field(pos, Field::empty());
-                            }
-                        }
-                    }
-                },
-                None => {
-                    return WhatHappened::RanOutOfStack;
+            match self.state.step()? {
+                StepEvent::Placed { .. } => {
+                    self.emitted += 1;
+                    return Some((*self.state.board(), self.state.is_solved()));
                 }
+                StepEvent::Cleared { .. } => continue,
             }
         }
     }
+
+    /// The lower bound is the number of boards left up to and including the first
+    /// solution, which is 0 once it has been emitted or if there is no solution. Finding
+    /// it means running the search to the first solution on a copy of the iterator, which
+    /// is done on the first call only. There is no useful upper bound.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let boards_to_first_solution = self.boards_to_first_solution.get_or_init(|| {
+            let mut state = self.initial_state.clone();
+            let mut boards = 0;
+
+            while let Some(event) = state.step() {
+                if let StepEvent::Placed { .. } = event {
+                    boards += 1;
+
+                    if state.is_solved() {
+                        return Some(boards);
+                    }
+                }
+            }
+
+            None
+        });
+
+        let lower =
+            boards_to_first_solution.map_or(0, |boards| boards.saturating_sub(self.emitted));
+
+        (lower, None)
+    }
 }
 
-impl Iterator for BacktrackingIter {
-    type Item = (Board, bool);
+/// Once all possibilities have been tried the solver has nothing left to work on,
+/// so the iterator keeps emitting None
+impl FusedIterator for BacktrackingIter {}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // If there's a next empty field, prepare the stack for that field, otherwise
-        // just keep executing the stack.
-        if let Some(next_empty_field) = self.board.next_empty_field(self.current_position) {
-            self.prepare_stack(next_empty_field);
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The "sudokus/oneeighty.txt" board
+    const TEST_SUDOKU: &str = "-349---28
+                               2-------6
+                               ---271---
+                               -----2-6-
+                               45-----39
+                               -6-4-----
+                               ---614---
+                               3-------1
+                               98---364-";
+
+    #[test]
+    fn cloned_iterator_continues_identically() {
+        let mut iterator = Board::try_from(TEST_SUDOKU).unwrap().solve_iter();
+
+        for _ in 0..100 {
+            iterator.next();
         }
 
-        match self.execute_stack() {
-            WhatHappened::PutNewFieldOnBoard => {
-                // After the new field is put on the board, check to see if more fields are available.
-                // If not, then we consider the board solved.
-                let board_is_solved = self.board.next_empty_field(self.current_position).is_none();
+        let cloned = iterator.clone();
+
+        assert!(iterator.eq(cloned));
+    }
 
-                Some((self.board, board_is_solved))
+    #[test]
+    fn depth_follows_the_search() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let mut iterator = board.solve_iter();
+        assert_eq!(iterator.depth(), 0);
+
+        iterator.next();
+        assert_eq!(iterator.depth(), 1);
+
+        let mut depths = Vec::new();
+
+        while let Some((_, is_solved)) = iterator.next() {
+            depths.push(iterator.depth());
+
+            if is_solved {
+                break;
+            }
+        }
+
+        // The solver backtracks along the way, and ends with every empty field filled in
+        assert!(depths.windows(2).any(|pair| pair[1] < pair[0]));
+        assert_eq!(depths.last(), Some(&board.empty_positions().count()));
+    }
+
+    #[test]
+    fn into_board_gives_the_latest_emitted_board() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let mut iterator = board.solve_iter();
+        assert_eq!(iterator.board(), &board);
+
+        let (latest, _) = iterator.next().unwrap();
+        assert_eq!(iterator.board(), &latest);
+
+        for (_, is_solved) in iterator.by_ref() {
+            if is_solved {
+                break;
+            }
+        }
+
+        assert_eq!(iterator.into_board(), board.first_solution().unwrap());
+    }
+
+    #[test]
+    fn size_hint_counts_the_boards_up_to_the_first_solution() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let mut iterator = board.solve_iter();
+        assert_eq!(iterator.size_hint(), (229, None));
+
+        for _ in 0..100 {
+            iterator.next();
+        }
+
+        assert_eq!(iterator.size_hint(), (129, None));
+
+        for (_, is_solved) in iterator.by_ref() {
+            if is_solved {
+                break;
             }
-            WhatHappened::RanOutOfStack => None,
         }
+
+        assert_eq!(iterator.size_hint(), (0, None));
+
+        iterator.reset();
+        assert_eq!(iterator.size_hint(), (229, None));
+    }
+
+    #[test]
+    fn size_hint_is_zero_without_a_solution() {
+        // No digit fits in the first field of the second row
+        let board = Board::try_from(
+            "-23456789
+             ---------
+             1--------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------",
+        )
+        .unwrap();
+
+        assert_eq!(board.solve_iter().size_hint(), (0, None));
+        assert_eq!(board.solve_iter().count(), 0);
+
+        // A locked empty field is never filled
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let locked = [Position { row: 0, column: 1 }].into_iter().collect();
+
+        assert_eq!(board.solve_iter_locked(&locked).size_hint(), (0, None));
+    }
+
+    #[test]
+    fn keeps_emitting_none_when_exhausted() {
+        let mut iterator = Board::try_from(TEST_SUDOKU).unwrap().solve_iter();
+
+        while iterator.next().is_some() {}
+
+        assert_eq!(iterator.size_hint(), (0, None));
+
+        for _ in 0..5 {
+            assert_eq!(iterator.next(), None);
+        }
+    }
+
+    #[test]
+    fn reset_iterator_starts_over() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let mut iterator = board.solve_iter();
+
+        for _ in 0..100 {
+            iterator.next();
+        }
+
+        iterator.reset();
+
+        assert!(iterator.eq(board.solve_iter()));
     }
 }
@@ -0,0 +1,37 @@
+use crate::{error::SudokuSolveError, Board};
+
+/// Solve a batch of boards, lazily emitting the first solution for each board in order.
+///
+/// Boards without a solution emit `SudokuSolveError::Contradiction` or
+/// `SudokuSolveError::Exhausted`, like `Board::first_solution`.
+pub fn solve_many(
+    boards: impl IntoIterator<Item = Board>,
+) -> impl Iterator<Item = Result<Board, SudokuSolveError>> {
+    boards.into_iter().map(Board::first_solution)
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solves_every_board_in_order() {
+        let boards = [
+            include_str!("../sudokus/oneeighty.txt"),
+            include_str!("../sudokus/starry.txt"),
+            include_str!("../sudokus/turbine.txt"),
+        ]
+        .map(|board| Board::try_from(board).unwrap());
+
+        let solutions = solve_many(boards).collect::<Vec<Result<Board, SudokuSolveError>>>();
+
+        assert_eq!(
+            solutions,
+            boards
+                .into_iter()
+                .map(Board::first_solution)
+                .collect::<Vec<_>>()
+        );
+    }
+}
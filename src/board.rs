@@ -1,389 +1,3034 @@
-use std::{collections::HashSet, fmt::Display};
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
-use crate::{error::FieldParseError, position_iter::PositionIter, SudokuSolveError};
+use crate::{
+    candidate_grid::CandidateGrid,
+    conflict::{Conflict, ConflictKind},
+    consecutive_marks::ConsecutiveMarks,
+    constraints::Constraints,
+    difficulty::Difficulty,
+    error::{FieldParseError, SudokuGenerateError},
+    grade::Grade,
+    hint::{Hint, Technique},
+    inequalities::Inequalities,
+    position_iter::PositionIter,
+    rng::{shuffle, Rng},
+    solution_counter::SolutionCounter,
+    symmetry::Symmetry,
+    unit::Unit,
+    SudokuSolveError, SudokuTransformError,
+};
 
 use super::{
-    backtracking_iter::BacktrackingIter, error::SudokuParseError, field::Field, position::Position,
+    backtracking_iter::BacktrackingIter,
+    error::SudokuParseError,
+    field::Field,
+    position::Position,
+    solver_state::{SolverState, StepEvent},
 };
 
+/// Number of minimizations tried by `enumerate_minimal` for every puzzle asked for
+const MINIMAL_ATTEMPTS_PER_PUZZLE: usize = 10;
+
+/// Number of iterations between each check of the clock in `first_solution_timeout`
+const TIMEOUT_CHECK_INTERVAL: usize = 1024;
+
 /// The Sudoku Board
 ///
 /// The board always contains valid fields and cannot violate the sudoku rules,
 /// for example it is never possible to have the same digit twice in a square.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct Board([[Field; 9]; 9]);
+///
+/// The board remembers which fields were filled when it was created, also known as
+/// the givens. Two boards are equal when their fields are equal, regardless of givens.
+#[derive(Clone, Copy, Debug)]
+pub struct Board {
+    fields: [[Field; 9]; 9],
+    /// Bitmask of the given fields, where bit n is the field with index n
+    givens: u128,
+}
+
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields
+    }
+}
+
+impl Eq for Board {}
+
+/// Hashes the fields only, like `PartialEq`. Hashes from the standard library hashers are
+/// not stable across runs or versions, so use `Board::board_id` for a key to store.
+impl Hash for Board {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fields.hash(state);
+    }
+}
 
 impl Board {
+    /// The fewest clues a sudoku with a unique solution can have. It has been proven that
+    /// no sudoku with 16 clues has a unique solution.
+    pub const MIN_CLUES: usize = 17;
+
+    /// The number of sudokus `generate_difficulty` generates before giving up on the
+    /// target difficulty
+    pub const GENERATE_ATTEMPTS: usize = 100;
+
+    /// Create a `Board` with only empty fields
+    pub(crate) fn empty() -> Board {
+        Board {
+            fields: [[Field::empty(); 9]; 9],
+            givens: 0,
+        }
+    }
+
+    /// Make all filled fields givens, like when a `Board` is created
+    fn with_filled_fields_as_givens(mut self) -> Board {
+        self.givens = PositionIter::from_first_field()
+            .filter(|position| self.is_filled_at(*position))
+            .fold(0, |givens, position| givens | 1 << position.to_index());
+
+        self
+    }
+
     ////////////////
     // Accessors //
     //////////////
 
     /// Get the `Field` at a given `Position`
     pub fn get_field(&self, position: Position) -> &Field {
-        &self.0[position.row][position.column]
+        &self.fields[position.row][position.column]
+    }
+
+    /// Is the field at a given `Position` filled?
+    pub fn is_filled_at(&self, position: Position) -> bool {
+        self.get_field(position).is_filled()
+    }
+
+    /// Is the field at a given `Position` empty?
+    pub fn is_empty_at(&self, position: Position) -> bool {
+        self.get_field(position).is_empty()
     }
 
     /// Put a `Field` on the `Board`
     pub(crate) fn put_field(&mut self, position: Position, sudoku_field: Field) {
-        self.0[position.row][position.column] = sudoku_field;
+        self.fields[position.row][position.column] = sudoku_field;
     }
 
     /// Given a `Position`, get the next free `Field`
     pub(crate) fn next_empty_field(&self, position: Position) -> Option<Position> {
-        PositionIter::new(position).find(|position| self.get_field(*position).is_empty())
+        PositionIter::new(position).find(|position| self.is_empty_at(*position))
     }
 
-    ////////////////
-    // Solutions //
-    //////////////
+    /// Get the `Position`s of the givens, which are the fields that were filled when the
+    /// `Board` was created. Fields filled by solving are not givens.
+    pub fn givens(&self) -> HashSet<Position> {
+        PositionIter::from_first_field()
+            .filter(|position| self.is_given(*position))
+            .collect()
+    }
 
-    /// Get the first solution for a `Board`
-    pub fn first_solution(self) -> Result<Board, SudokuSolveError> {
-        if let Some(solution) = self
-            .solve_iter()
-            .find(|(_, is_solved)| *is_solved)
-            .map(|(board, _)| board)
-        {
-            Ok(solution)
-        } else {
-            Err(SudokuSolveError::Unsolvable)
-        }
+    /// Is the field at a `Position` a given? Renderers can use this to show the clues
+    /// differently from the digits filled in by solving.
+    pub fn is_given(&self, position: Position) -> bool {
+        self.givens & 1 << position.to_index() != 0
     }
 
-    /// Count solutions for a `Board`
-    ///
-    /// An almost empty sudoku will have many solutions, and calculating them all will
-    /// be an expensive operation. For that reason it is possible to specify a maximum
-    /// for both total iterations and total solutions counted.
-    ///
-    /// For instance, the consumer might not be interested in counting more than 100
-    /// solutions.
-    pub fn count_solutions(
-        self,
-        max_solutions: Option<usize>,
-        max_iterations: Option<usize>,
-    ) -> usize {
-        self.solve_iter()
-            .enumerate()
-            .take_while(|(index, _)| {
-                // Maximum iterations allowed
-                if let Some(max) = max_iterations {
-                    index < &max
-                } else {
-                    true
-                }
-            })
-            .filter(|(_, (_, is_solved))| *is_solved)
-            .enumerate()
-            .take_while(|(index, _)| {
-                // Maximum solutions allowed
-                if let Some(max) = max_solutions {
-                    index < &max
-                } else {
-                    true
-                }
-            })
-            .count()
+    /// Get the `Position` and `Field` of every filled field in row-major order. This is
+    /// the sparse form of the `Board`, which `Board::from_clues` creates it from again.
+    pub fn clues(&self) -> Vec<(Position, Field)> {
+        PositionIter::from_first_field()
+            .filter(|position| self.is_filled_at(*position))
+            .map(|position| (position, *self.get_field(position)))
+            .collect()
     }
 
-    /// Iterator emitting `(board: Board, is_solved: Bool)` on the way towards
-    /// a solution using the backtracking technique
-    pub fn solve_iter(self) -> BacktrackingIter {
-        BacktrackingIter::new(self)
+    /// Iterate over the `Position`s of all empty fields in row-major order
+    pub fn empty_positions(&self) -> impl Iterator<Item = Position> + '_ {
+        PositionIter::from_first_field().filter(|position| self.is_empty_at(*position))
     }
 
-    /////////////////
-    // Validation //
-    ///////////////
+    /// Iterate over the fields of the nine 3x3 boxes in reading order, from the top-left
+    /// box to the bottom-right box. The fields of each box are also in reading order.
+    pub fn boxes(&self) -> impl Iterator<Item = [Field; 9]> + '_ {
+        (0..9).map(|index| {
+            Unit::Box(index)
+                .positions()
+                .map(|position| *self.get_field(position))
+        })
+    }
 
-    /// Do any digits in the `Board` violate the sudoku rules? For instance it is not
-    /// valid to have the digit 5 twice in a row on the board.
-    fn rule_violations(self) -> HashSet<Position> {
-        PositionIter::from_first_field()
-            .map(|position| (position, self.get_field(position)))
-            .filter(|(_, field)| field.is_filled())
-            .filter(|(pos, field)| {
-                let mut temp_board = self;
-                temp_board.put_field(*pos, Field::empty());
-                !temp_board.valid_number_at_position(*pos, field)
-            })
-            .map(|(pos, _)| pos)
-            .collect::<HashSet<Position>>()
+    /// Count the givens in each 3x3 box, from the top-left box to the bottom-right box.
+    /// Useful for judging how evenly the clues of a puzzle are spread out.
+    pub fn clues_per_box(&self) -> [usize; 9] {
+        self.clues_per_unit(Unit::Box)
     }
 
-    /// Is a number valid at a given position?
-    /// Note: This assumes the field is not in the board yet
-    pub(crate) fn valid_number_at_position(&self, position: Position, number: &Field) -> bool {
-        !self.number_used_in_row(position, number)
-            && !self.number_used_in_column(position, number)
-            && !self.number_used_in_square(position, number)
+    /// Count the givens in each row, from top to bottom
+    pub fn clues_per_row(&self) -> [usize; 9] {
+        self.clues_per_unit(Unit::Row)
     }
 
-    /// Is a number currently used in a row?
-    fn number_used_in_row(&self, position: Position, number: &Field) -> bool {
-        let row_slice = &self.0[position.row];
-        row_slice.iter().any(|field| field == number)
+    /// Count the givens in each column, from left to right
+    pub fn clues_per_column(&self) -> [usize; 9] {
+        self.clues_per_unit(Unit::Column)
     }
 
-    /// Is a number currently used in a column?
-    fn number_used_in_column(&self, position: Position, number: &Field) -> bool {
-        (0..9)
-            .map(|row| Position {
-                row,
-                column: position.column,
-            })
-            .any(|position| self.get_field(position) == number)
+    /// Count how many times each digit 1-9 appears as a given, with the count of 1 first.
+    /// Useful for judging whether the clues of a puzzle are balanced.
+    pub fn count_clues_by_digit(&self) -> [usize; 9] {
+        let mut counts = [0; 9];
+
+        let given_values = PositionIter::from_first_field()
+            .filter(|position| self.is_given(*position))
+            .filter_map(|position| self.get_field(position).value());
+
+        for value in given_values {
+            counts[value as usize - 1] += 1;
+        }
+
+        counts
     }
 
-    /// Is a number used in a 3x3 square?
-    fn number_used_in_square(&self, position: Position, number: &Field) -> bool {
-        let square_row = position.row / 3;
-        let square_column = position.column / 3;
+    /// Count the givens in each of the nine units of one kind
+    fn clues_per_unit(&self, unit: fn(usize) -> Unit) -> [usize; 9] {
+        std::array::from_fn(|index| {
+            unit(index)
+                .positions()
+                .into_iter()
+                .filter(|position| self.is_given(*position))
+                .count()
+        })
+    }
 
-        (0..3)
-            .map(|row_increase| {
-                &self.0[square_row * 3 + row_increase][(square_column * 3)..(square_column * 3 + 3)]
+    /// Get every `Position` where two boards differ, together with the `Field` from
+    /// this board and the `Field` from the other board, in row-major order
+    pub fn diff(&self, other: &Board) -> Vec<(Position, Field, Field)> {
+        PositionIter::from_first_field()
+            .map(|position| {
+                (
+                    position,
+                    *self.get_field(position),
+                    *other.get_field(position),
+                )
             })
-            .any(|slice| slice.contains(number))
+            .filter(|(_, field, other_field)| field != other_field)
+            .collect()
     }
-}
 
-/// Create a `Board`  from a `String`
-impl TryFrom<String> for Board {
-    type Error = SudokuParseError;
+    /// Grade the filled fields of the `Board` against a solution, counting the correct,
+    /// incorrect and empty fields. The solution is expected to be completely filled.
+    pub fn grade_against(&self, solution: &Board) -> Grade {
+        let mut grade = Grade {
+            correct: 0,
+            incorrect: Vec::new(),
+            empty: 0,
+        };
 
-    fn try_from(input: String) -> Result<Self, Self::Error> {
-        Board::try_from(input.as_str())
-    }
-}
+        for position in PositionIter::from_first_field() {
+            let field = self.get_field(position);
 
-/// Create a `Board` from a `str`
-impl TryFrom<&str> for Board {
-    type Error = SudokuParseError;
+            if field.is_empty() {
+                grade.empty += 1;
+            } else if field == solution.get_field(position) {
+                grade.correct += 1;
+            } else {
+                grade.incorrect.push(position);
+            }
+        }
 
-    fn try_from(input: &str) -> Result<Self, Self::Error> {
-        let input_vector = input
-            .split_whitespace()
-            .collect::<String>()
-            .bytes()
-            .collect::<Vec<u8>>();
+        grade
+    }
 
-        Board::try_from(input_vector)
+    /// Is the `Board` the same as another `Board` up to relabeling of the digits? That is
+    /// the case when both have the same empty fields, and there is a one-to-one mapping of
+    /// digits turning the filled fields of one into the filled fields of the other.
+    pub fn is_relabeling_of(&self, other: &Board) -> bool {
+        // The digit each digit maps to in either direction, with index 0 unused
+        let mut forward = [0; 10];
+        let mut backward = [0; 10];
+
+        PositionIter::from_first_field().all(|position| {
+            match (
+                self.get_field(position).value(),
+                other.get_field(position).value(),
+            ) {
+                (None, None) => true,
+                (Some(digit), Some(other_digit)) => {
+                    let (digit, other_digit) = (digit as usize, other_digit as usize);
+
+                    if forward[digit] == 0 && backward[other_digit] == 0 {
+                        forward[digit] = other_digit;
+                        backward[other_digit] = digit;
+                    }
+
+                    forward[digit] == other_digit && backward[other_digit] == digit
+                }
+                _ => false,
+            }
+        })
     }
-}
 
-/// Create a `Board` from a vector of bytes
-impl TryFrom<Vec<u8>> for Board {
-    type Error = SudokuParseError;
+    /// Get the percentage of filled fields on the `Board`, 0-100.
+    ///
+    /// This is only an approximate progress indicator for boards emitted by `solve_iter`,
+    /// since backtracking clears fields again and the percentage goes up and down.
+    pub fn solved_percentage(&self) -> f32 {
+        let filled_count = 81 - self.empty_positions().count();
+        filled_count as f32 / 81.0 * 100.0
+    }
 
-    fn try_from(input: Vec<u8>) -> Result<Self, Self::Error> {
-        let prepared_vec = input
-            .iter()
-            .map(|c| match c {
-                b'-' | b' ' => None,
-                val if (49..=57).contains(val) => Some(val - 48),
-                _ => Some(255), // Use an invalid field value which will fail in the next step
+    /// Get all symmetries of the pattern of filled fields on the `Board`. Only which
+    /// fields are filled matters, not their values. An empty `Vec` means the pattern
+    /// has no symmetry.
+    pub fn symmetry_of_clues(&self) -> Vec<Symmetry> {
+        Symmetry::all()
+            .into_iter()
+            .filter(|symmetry| {
+                PositionIter::from_first_field().all(|position| {
+                    self.get_field(position).is_filled()
+                        == self.get_field(symmetry.mirror(position)).is_filled()
+                })
             })
-            .collect::<Vec<Option<u8>>>();
-
-        Board::try_from(prepared_vec)
+            .collect()
     }
-}
 
-/// Create a `Board` from a vector of `Option<u8>` where the u8 is a Some with digit 1-9
-/// and empty fields are represented as None
-impl TryFrom<Vec<Option<u8>>> for Board {
-    type Error = SudokuParseError;
+    ////////////////
+    // Solutions //
+    //////////////
 
-    fn try_from(input: Vec<Option<u8>>) -> Result<Self, Self::Error> {
-        if input.len() != 81 {
-            return Err(SudokuParseError::InvalidLength);
+    /// Get the first solution for a `Board`
+    pub fn first_solution(self) -> Result<Board, SudokuSolveError> {
+        if let Some(position) = self.find_contradiction() {
+            return Err(SudokuSolveError::Contradiction { position });
         }
 
-        // 1. Build up a board, treating invalid fields as empty fields,
-        //    while inserting them as validation errors in the HashSet.
-        let mut lenient_board = Board([[Field::empty(); 9]; 9]);
-        let mut positions_with_parse_errors = HashSet::new();
-
-        for (i, field) in input.iter().enumerate() {
-            let position = Position::from_index(i);
-
-            let parsed_field = match field {
-                Some(val) => match Field::new(*val) {
-                    Ok(field) => field,
-                    Err(_) => {
-                        positions_with_parse_errors
-                            .insert((position, FieldParseError::InvalidCharacter));
-                        Field::empty()
-                    }
-                },
-                None => Field::empty(),
-            };
-
-            lenient_board.put_field(position, parsed_field);
+        if let Some(solution) = self
+            .solve_iter()
+            .find(|(_, is_solved)| *is_solved)
+            .map(|(board, _)| board)
+        {
+            Ok(solution)
+        } else {
+            Err(SudokuSolveError::Exhausted)
         }
+    }
 
-        // 2. Check the leniently parsed board for rule violations
-        let rule_violations = lenient_board
-            .rule_violations()
-            .iter()
-            .map(|pos| (*pos, FieldParseError::SudokuRuleViolation))
-            .collect::<HashSet<(Position, FieldParseError)>>();
+    /// Get the first solution for a `Board`, calling `on_step` with every board on the way,
+    /// including the solution itself. Useful for reporting progress.
+    pub fn first_solution_with(
+        self,
+        mut on_step: impl FnMut(&Board),
+    ) -> Result<Board, SudokuSolveError> {
+        if let Some(position) = self.find_contradiction() {
+            return Err(SudokuSolveError::Contradiction { position });
+        }
 
-        let all_errors = positions_with_parse_errors
-            .union(&rule_violations)
-            .cloned()
-            .collect::<HashSet<(Position, FieldParseError)>>();
+        for (board, is_solved) in self.solve_iter() {
+            on_step(&board);
 
-        // If no errors, the board is valid
-        if all_errors.is_empty() {
-            Ok(lenient_board)
-        } else {
-            Err(SudokuParseError::ParseErrors(all_errors))
+            if is_solved {
+                return Ok(board);
+            }
         }
+
+        Err(SudokuSolveError::Exhausted)
     }
-}
 
-/// Get a `String` representation of a `Board`
-impl Display for Board {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "+-----------+")?;
+    /// Get the placements the solver kept on the way to the first solution, leaving out
+    /// the ones it backtracked from. The placements are in the order they became
+    /// permanent, so putting them on the `Board` one by one replays the solve.
+    pub fn solution_path(self) -> Result<Vec<(Position, Field)>, SudokuSolveError> {
+        if let Some(position) = self.find_contradiction() {
+            return Err(SudokuSolveError::Contradiction { position });
+        }
 
-        for row in 0..=8 {
-            write!(f, "|")?;
+        let mut state = SolverState::new(self);
+        let mut path: Vec<(Position, Field)> = Vec::new();
 
-            for column in 0..=8 {
-                write!(f, "{}", self.0[row][column])?;
+        while let Some(event) = state.step() {
+            // The solver only ever changes its latest placement, which is the last one
+            // on the path
+            let changes_last = |position| path.last().is_some_and(|(last, _)| *last == position);
 
-                if (column + 1) % 3 == 0 {
-                    write!(f, "|")?;
-                }
-            }
+            match event {
+                StepEvent::Placed { position, field } => {
+                    if changes_last(position) {
+                        path.pop();
+                    }
 
-            writeln!(f)?;
+                    path.push((position, field));
 
-            if (row + 1) % 3 == 0 && row != 8 {
-                writeln!(f, "+---+---+---+")?;
+                    if state.is_solved() {
+                        return Ok(path);
+                    }
+                }
+                StepEvent::Cleared { position } => {
+                    if changes_last(position) {
+                        path.pop();
+                    }
+                }
             }
         }
 
-        writeln!(f, "+-----------+")?;
-
-        Ok(())
+        Err(SudokuSolveError::Exhausted)
     }
-}
 
-#[allow(clippy::unwrap_used, clippy::expect_used)]
-#[cfg(test)]
-mod accessor_tests {
-    use super::*;
+    /// Get the first solution for a `Board`, giving up with `TimedOut` once solving has
+    /// taken longer than the deadline.
+    ///
+    /// The clock is only checked every `TIMEOUT_CHECK_INTERVAL` iterations, so a board
+    /// solved in fewer iterations is never timed out, and the deadline may be overshot
+    /// by the time those iterations take.
+    pub fn first_solution_timeout(self, deadline: Duration) -> Result<Board, SudokuSolveError> {
+        if let Some(position) = self.find_contradiction() {
+            return Err(SudokuSolveError::Contradiction { position });
+        }
 
-    #[test]
-    fn gets_a_field() {
-        let board = Board::try_from(
-            "1--------
-             -2-------
-             --3------
-             ---4-----
-             ----5----
-             -----6---
-             ------7--
-             -------8-
-             --------9
-        ",
-        )
-        .unwrap();
+        let started = Instant::now();
 
-        assert_eq!(
-            board.get_field(Position { row: 0, column: 0 }),
-            &Field::from_u8(1)
-        );
+        for (index, (board, is_solved)) in self.solve_iter().enumerate() {
+            if is_solved {
+                return Ok(board);
+            }
 
-        assert_eq!(
-            board.get_field(Position { row: 8, column: 8 }),
-            &Field::from_u8(9)
-        );
+            if (index + 1) % TIMEOUT_CHECK_INTERVAL == 0 && started.elapsed() >= deadline {
+                return Err(SudokuSolveError::TimedOut);
+            }
+        }
 
-        assert_eq!(
-            board.get_field(Position { row: 8, column: 7 }),
-            &Field::empty()
-        );
+        Err(SudokuSolveError::Exhausted)
     }
 
-    #[test]
-    fn puts_a_field() {
-        let mut board = Board([[Field::empty(); 9]; 9]);
+    /// Get the solution for a `Board` only if it is the only one. Fails with
+    /// `MultipleSolutions` if the board has more than one solution.
+    pub fn unique_solution(self) -> Result<Board, SudokuSolveError> {
+        if self.empty_positions().next().is_none() {
+            return Ok(self);
+        }
 
-        assert_eq!(
-            board.get_field(Position { row: 1, column: 3 }),
-            &Field::empty()
-        );
+        if let Some(position) = self.find_contradiction() {
+            return Err(SudokuSolveError::Contradiction { position });
+        }
 
-        board.put_field(Position { row: 1, column: 3 }, Field::from_u8(2));
+        let mut solutions = self.solutions();
 
+        match (solutions.next(), solutions.next()) {
+            (Some(solution), None) => Ok(solution),
+            (Some(_), Some(_)) => Err(SudokuSolveError::MultipleSolutions),
+            (None, _) => Err(SudokuSolveError::Exhausted),
+        }
+    }
+
+    /// Iterator emitting every solution for a `Board`
+    pub fn solutions(self) -> impl Iterator<Item = Board> {
+        self.solve_iter()
+            .filter(|(_, is_solved)| *is_solved)
+            .map(|(board, _)| board)
+    }
+
+    /// Get the first solution for a `Board` by filling in naked and hidden singles until
+    /// stuck, and only then guessing. Guesses are made on the empty field with the fewest
+    /// candidates, and the singles are filled in again after each guess.
+    ///
+    /// This is much faster than `first_solution` on boards that are mostly solvable by
+    /// logic. For boards with a unique solution both find the same solution.
+    pub fn solve_fast(self) -> Result<Board, SudokuSolveError> {
+        if let Some(position) = self.find_contradiction() {
+            return Err(SudokuSolveError::Contradiction { position });
+        }
+
+        self.propagate_and_search()
+            .ok_or(SudokuSolveError::Exhausted)
+    }
+
+    /// Fill in singles, then recursively guess on the field with the fewest candidates
+    fn propagate_and_search(mut self) -> Option<Board> {
+        while self.fill_obvious() {}
+
+        let mut fewest_candidates: Option<(Position, Vec<Field>)> = None;
+
+        for position in self.empty_positions() {
+            let candidates = self.candidates(position);
+
+            match &fewest_candidates {
+                // A field without candidates means an earlier guess was wrong
+                _ if candidates.is_empty() => return None,
+                Some((_, fewest)) if fewest.len() <= candidates.len() => {}
+                _ => fewest_candidates = Some((position, candidates)),
+            }
+        }
+
+        match fewest_candidates {
+            // No empty fields left, so the board is solved
+            None => Some(self),
+            Some((position, candidates)) => candidates.into_iter().find_map(|field| {
+                let mut guess = self;
+                guess.put_field(position, field);
+                guess.propagate_and_search()
+            }),
+        }
+    }
+
+    /// Count solutions for a `Board`
+    ///
+    /// An almost empty sudoku will have many solutions, and calculating them all will
+    /// be an expensive operation. For that reason it is possible to specify a maximum
+    /// for both total iterations and total solutions counted.
+    ///
+    /// For instance, the consumer might not be interested in counting more than 100
+    /// solutions.
+    pub fn count_solutions(
+        self,
+        max_solutions: Option<usize>,
+        max_iterations: Option<usize>,
+    ) -> usize {
+        if self.find_contradiction().is_some() {
+            return 0;
+        }
+
+        SolutionCounter::new(self)
+            .count(0, max_solutions, max_iterations)
+            .0
+    }
+
+    /// Count solutions for a `Board` like `count_solutions`, while collecting the first
+    /// solutions found, up to `collect_up_to`. This avoids searching twice to get both the
+    /// count and some of the solutions.
+    pub fn count_and_collect(
+        self,
+        collect_up_to: usize,
+        max_iterations: Option<usize>,
+    ) -> (usize, Vec<Board>) {
+        if self.find_contradiction().is_some() {
+            return (0, Vec::new());
+        }
+
+        SolutionCounter::new(self).count(collect_up_to, None, max_iterations)
+    }
+
+    /// Does the `Board` have exactly one solution? A completely filled board is its own
+    /// unique solution.
+    pub fn has_unique_solution(&self) -> bool {
+        self.empty_positions().next().is_none() || self.count_solutions(Some(2), None) == 1
+    }
+
+    /// Is the `Board` a minimal sudoku? A minimal sudoku has a unique solution, and
+    /// removing any single filled field would give it more than one solution.
+    ///
+    /// This solves the board once for every filled field, so it is an expensive check.
+    pub fn is_minimal(&self) -> bool {
+        self.has_unique_solution()
+            && PositionIter::from_first_field()
+                .filter(|position| self.get_field(*position).is_filled())
+                .all(|position| {
+                    let mut reduced_board = *self;
+                    reduced_board.put_field(position, Field::empty());
+                    !reduced_board.has_unique_solution()
+                })
+    }
+
+    /// Remove as many filled fields as possible while keeping the solution unique, giving
+    /// a minimal sudoku with the same solution. The filled fields are tried in a random
+    /// order determined by the seed, so the same seed always gives the same result, while
+    /// other seeds may give other minimal sudokus. The filled fields left are the givens
+    /// of the minimized `Board`.
+    ///
+    /// A `Board` without a unique solution has no fields that can be removed, and is
+    /// returned unchanged. This solves the board once for every filled field, so it is an
+    /// expensive operation.
+    pub fn minimize(self, seed: u64) -> Board {
+        if !self.has_unique_solution() {
+            return self;
+        }
+
+        let mut filled_positions = PositionIter::from_first_field()
+            .filter(|position| self.is_filled_at(*position))
+            .collect::<Vec<Position>>();
+        shuffle(&mut filled_positions, &mut Rng::new(seed));
+
+        let mut minimized = self;
+
+        for position in filled_positions {
+            let mut reduced_board = minimized;
+            reduced_board.put_field(position, Field::empty());
+
+            if reduced_board.has_unique_solution() {
+                minimized = reduced_board;
+            }
+        }
+
+        minimized.with_filled_fields_as_givens()
+    }
+
+    /// Find up to `count` minimal sudokus with the same unique solution as the `Board`,
+    /// usually a solved board. Each one is minimized with a new random order from the
+    /// seed, and sudokus that are equivalent to one already found are skipped, judged by
+    /// their `canonical` form.
+    ///
+    /// Gives up after 10 attempts for each sudoku asked for, so fewer sudokus may be
+    /// returned. A `Board` without a unique solution gives none. Both minimizing and
+    /// finding the canonical form are expensive, so this is slow.
+    pub fn enumerate_minimal(self, seed: u64, count: usize) -> Vec<Board> {
+        if !self.has_unique_solution() {
+            return Vec::new();
+        }
+
+        let mut rng = Rng::new(seed);
+        let mut canonical_forms = HashSet::new();
+        let mut minimal_boards = Vec::new();
+
+        for _ in 0..count.saturating_mul(MINIMAL_ATTEMPTS_PER_PUZZLE) {
+            if minimal_boards.len() == count {
+                break;
+            }
+
+            let minimal_board = self.minimize(rng.next_u64());
+
+            if canonical_forms.insert(minimal_board.canonical()) {
+                minimal_boards.push(minimal_board);
+            }
+        }
+
+        minimal_boards
+    }
+
+    /// Remove a random given while keeping the solution unique, returning its `Position`.
+    /// The same seed always removes the same given. This is a single step of `minimize`,
+    /// making the sudoku a little harder. Fields filled in by solving are never removed.
+    ///
+    /// Returns `None` and leaves the `Board` unchanged if no given can be removed, which
+    /// is the case for minimal sudokus and boards without a unique solution.
+    pub fn remove_random_clue(&mut self, seed: u64) -> Option<Position> {
+        if !self.has_unique_solution() {
+            return None;
+        }
+
+        let mut given_positions = PositionIter::from_first_field()
+            .filter(|position| self.is_given(*position))
+            .collect::<Vec<Position>>();
+        shuffle(&mut given_positions, &mut Rng::new(seed));
+
+        let position = given_positions.into_iter().find(|position| {
+            let mut reduced_board = *self;
+            reduced_board.put_field(*position, Field::empty());
+            reduced_board.has_unique_solution()
+        })?;
+
+        self.put_field(position, Field::empty());
+        self.givens &= !(1 << position.to_index());
+
+        Some(position)
+    }
+
+    /// Fill a random empty field with its digit from the unique solution, returning its
+    /// `Position`. The field becomes a given, making the sudoku a little easier. The same
+    /// seed always fills the same field.
+    ///
+    /// Returns `None` and leaves the `Board` unchanged if it is already full or doesn't
+    /// have a unique solution.
+    pub fn add_clue(&mut self, seed: u64) -> Option<Position> {
+        let (position, field) = self.reveal(seed)?;
+        self.put_field(position, field);
+        self.givens |= 1 << position.to_index();
+
+        Some(position)
+    }
+
+    /// Get the correct `Field` for a random empty field, taken from the unique solution,
+    /// without changing the `Board`. The same seed always reveals the same field. Unlike
+    /// `hint`, this needs no logic, so it helps a player who is completely stuck.
+    ///
+    /// Returns `None` if the `Board` is already full or doesn't have a unique solution.
+    pub fn reveal(self, seed: u64) -> Option<(Position, Field)> {
+        let solution = self.unique_solution().ok()?;
+
+        let mut empty_positions = self.empty_positions().collect::<Vec<Position>>();
+        shuffle(&mut empty_positions, &mut Rng::new(seed));
+
+        let position = empty_positions.first().copied()?;
+
+        Some((position, *solution.get_field(position)))
+    }
+
+    /// Find the filled fields that are not givens and disagree with the unique solution of
+    /// the givens, in row-major order. These are the digits placed by a player that are
+    /// wrong, even if they don't break the sudoku rules yet.
+    ///
+    /// Fails like `unique_solution` if the givens alone don't have a unique solution.
+    pub fn invalid_placements(self) -> Result<Vec<Position>, SudokuSolveError> {
+        let mut givens_only = self;
+
+        for position in PositionIter::from_first_field() {
+            if !self.is_given(position) {
+                givens_only.put_field(position, Field::empty());
+            }
+        }
+
+        let solution = givens_only.unique_solution()?;
+
+        Ok(givens_only
+            .empty_positions()
+            .filter(|position| {
+                self.is_filled_at(*position)
+                    && self.get_field(*position) != solution.get_field(*position)
+            })
+            .collect())
+    }
+
+    /// Iterator emitting `(board: Board, is_solved: Bool)` on the way towards
+    /// a solution using the backtracking technique
+    pub fn solve_iter(self) -> BacktrackingIter {
+        BacktrackingIter::new(self)
+    }
+
+    /// Iterator like `solve_iter`, but using forward checking: a digit is only placed if
+    /// every empty field sharing a row, column or box with it still has a candidate.
+    ///
+    /// The boards emitted follow the same contract as `solve_iter` and the solutions are
+    /// found in the same order, but dead ends are pruned earlier, so fewer boards are
+    /// emitted on the way. Each step does more work, so this is not always faster.
+    pub fn solve_iter_fc(self) -> BacktrackingIter {
+        BacktrackingIter::from_state(SolverState::new(self).with_forward_checking())
+    }
+
+    /// Iterator like `solve_iter`, but only emitting every nth board. Solved boards are
+    /// always emitted, regardless of where they fall in the sampling, so animations
+    /// never miss the solution. An `n` of 0 is treated as 1.
+    pub fn solve_iter_sampled(self, n: usize) -> impl Iterator<Item = (Board, bool)> {
+        let n = n.max(1);
+
+        self.solve_iter()
+            .enumerate()
+            .filter(move |(index, (_, is_solved))| *is_solved || (index + 1) % n == 0)
+            .map(|(_, item)| item)
+    }
+
+    /// Iterator like `solve_iter`, but ending once the `cancel` flag is set, so another
+    /// thread can stop a long search. The flag is checked before every step, and once the
+    /// iterator has ended it stays ended, even if the flag is cleared again.
+    pub fn solve_iter_cancellable(
+        self,
+        cancel: Arc<AtomicBool>,
+    ) -> impl Iterator<Item = (Board, bool)> {
+        let mut iterator = self.solve_iter();
+
+        std::iter::from_fn(move || {
+            if cancel.load(atomic::Ordering::Relaxed) {
+                None
+            } else {
+                iterator.next()
+            }
+        })
+        .fuse()
+    }
+
+    /// Iterator like `solve_iter`, but emitting the `Board` itself as `(board, false)`
+    /// before the first step, so animations can show the puzzle before solving begins
+    pub fn solve_iter_with_initial(self) -> impl Iterator<Item = (Board, bool)> {
+        std::iter::once((self, false)).chain(self.solve_iter())
+    }
+
+    /// Iterator emitting `(board: Board, is_solved: Bool, changed: Option<Position>)` like
+    /// `solve_iter`, together with the `Position` of the field changed in the step.
+    ///
+    /// Unlike `solve_iter`, a board is also emitted every time a field is cleared when
+    /// backtracking. Each board then differs from the previous one in exactly the changed
+    /// field, so visualizations can update a single field instead of diffing boards.
+    pub fn solve_iter_verbose(self) -> impl Iterator<Item = (Board, bool, Option<Position>)> {
+        let mut state = SolverState::new(self);
+
+        std::iter::from_fn(move || {
+            let changed = match state.step()? {
+                StepEvent::Placed { position, .. } | StepEvent::Cleared { position } => position,
+            };
+
+            Some((*state.board(), state.is_solved(), Some(changed)))
+        })
+    }
+
+    /// Iterator like `solve_iter`, but trying the digits for each field in the given
+    /// order instead of from 1 to 9.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the order is not a permutation of 1-9.
+    pub fn solve_iter_with_order(self, order: [u8; 9]) -> BacktrackingIter {
+        let mut sorted_order = order;
+        sorted_order.sort_unstable();
+
+        assert!(
+            sorted_order == [1, 2, 3, 4, 5, 6, 7, 8, 9],
+            "The digit order {order:?} is not a permutation of 1-9"
+        );
+
+        BacktrackingIter::from_state(SolverState::new(self).with_order(order))
+    }
+
+    /// Iterator like `solve_iter`, but never changing the fields at the locked positions.
+    ///
+    /// Filled fields are never cleared by backtracking, so locked filled fields stay fixed,
+    /// including guesses that are not givens. If the locked fields make the board
+    /// unsolvable, the iterator ends without emitting a solution. A locked empty field
+    /// can never be filled, so then the iterator emits nothing at all.
+    pub fn solve_iter_locked(self, locked: &HashSet<Position>) -> BacktrackingIter {
+        BacktrackingIter::from_state(SolverState::new(self).with_locked(locked))
+    }
+
+    /// Iterator like `solve_iter` for a variant sudoku, where the digits must also respect
+    /// the extra constraints. If the filled fields already break a constraint, the
+    /// iterator ends without emitting anything.
+    pub fn solve_iter_with(self, constraints: Constraints) -> BacktrackingIter {
+        BacktrackingIter::from_state(SolverState::new(self).with_constraints(constraints))
+    }
+
+    /// Iterator like `solve_iter` for a greater-than sudoku, where the digits must also
+    /// respect the inequality signs between adjacent fields. If the filled fields already
+    /// break a sign, the iterator ends without emitting anything.
+    pub fn solve_iter_inequalities(self, inequalities: &Inequalities) -> BacktrackingIter {
+        self.solve_iter_with(Constraints::new().with_inequalities(inequalities.clone()))
+    }
+
+    /// Iterator like `solve_iter` for a consecutive sudoku, where adjacent digits must
+    /// differ by exactly 1 across a marked border, and must not across any other border.
+    /// If the filled fields already break a border, the iterator ends without emitting
+    /// anything.
+    pub fn solve_iter_consecutive(self, marks: &ConsecutiveMarks) -> BacktrackingIter {
+        self.solve_iter_with(Constraints::new().with_consecutive_marks(*marks))
+    }
+
+    /// Iterator like `solve_iter` for an anti-knight sudoku, where the same digit may also
+    /// not appear a chess knight's move apart. If the filled fields already break this,
+    /// the iterator ends without emitting anything.
+    pub fn solve_iter_anti_knight(self) -> BacktrackingIter {
+        self.solve_iter_with(Constraints::new().with_anti_knight())
+    }
+
+    /// Iterator like `solve_iter` for an anti-king sudoku, where the same digit may also
+    /// not appear in diagonally adjacent fields. If the filled fields already break this,
+    /// the iterator ends without emitting anything.
+    pub fn solve_iter_anti_king(self) -> BacktrackingIter {
+        self.solve_iter_with(Constraints::new().with_anti_king())
+    }
+
+    /// Iterator like `solve_iter`, but starting the search at a `Position` instead of
+    /// the first field. Useful for re-solving part of a board after a local edit.
+    ///
+    /// Empty fields before `start` are left as they are, so a board is reported as
+    /// solved once every field from `start` and onwards is filled.
+    pub fn solve_iter_from(self, start: Position) -> BacktrackingIter {
+        BacktrackingIter::starting_at(self, start)
+    }
+
+    /////////////////
+    // Candidates //
+    ///////////////
+
+    /// Get the candidates for a `Position`, which are the `Field`s that can be put there
+    /// without violating the sudoku rules. A filled field has no candidates.
+    pub fn candidates(&self, position: Position) -> Vec<Field> {
+        if self.get_field(position).is_filled() {
+            return Vec::new();
+        }
+
+        Field::all()
+            .into_iter()
+            .filter(|field| self.valid_number_at_position(position, field))
+            .collect()
+    }
+
+    /// Find every empty field that has exactly one candidate, also known as naked singles.
+    /// Returns the positions together with the only `Field` that can be put there.
+    pub fn find_single_candidates(&self) -> Vec<(Position, Field)> {
+        self.empty_positions()
+            .filter_map(|position| match self.candidates(position)[..] {
+                [field] => Some((position, field)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Find every digit that has only one place left in a row, column or box, also known
+    /// as hidden singles. Returns the positions together with the `Field` to put there.
+    pub fn find_hidden_singles(&self) -> Vec<(Position, Field)> {
+        self.hidden_singles()
+            .into_iter()
+            .map(|(position, field, _)| (position, field))
+            .collect()
+    }
+
+    /// Find hidden singles together with the unit they were found in. A field is only
+    /// reported once, even if it is a hidden single in more than one unit.
+    fn hidden_singles(&self) -> Vec<(Position, Field, Unit)> {
+        let mut singles: Vec<(Position, Field, Unit)> = Vec::new();
+
+        for unit in Unit::all() {
+            let positions = unit.positions();
+
+            for field in Field::all() {
+                if positions.iter().any(|pos| self.get_field(*pos) == &field) {
+                    continue;
+                }
+
+                let mut places = positions.iter().filter(|pos| {
+                    self.get_field(**pos).is_empty() && self.valid_number_at_position(**pos, &field)
+                });
+
+                if let (Some(position), None) = (places.next(), places.next()) {
+                    if !singles.iter().any(|(p, f, _)| p == position && f == &field) {
+                        singles.push((*position, field, unit));
+                    }
+                }
+            }
+        }
+
+        singles
+    }
+
+    /// Get the easiest logical move on the board, or `None` if only guessing remains.
+    ///
+    /// Naked singles are considered easier than hidden singles.
+    pub fn hint(&self) -> Option<Hint> {
+        if let Some((position, field)) = self.find_single_candidates().first() {
+            return Some(Hint {
+                technique: Technique::NakedSingle,
+                position: *position,
+                field: *field,
+                reason: format!("{field} is the only digit that fits in {position}"),
+            });
+        }
+
+        self.hidden_singles()
+            .first()
+            .map(|(position, field, unit)| Hint {
+                technique: Technique::HiddenSingle,
+                position: *position,
+                field: *field,
+                reason: format!("{field} can only go in {position} within {unit}"),
+            })
+    }
+
+    /// Apply a single round of naked and hidden singles to the `Board`. Returns whether
+    /// any field was filled.
+    ///
+    /// The singles are found before any of them are put on the board, so new singles
+    /// created by this round are left for the next call. A single that would violate
+    /// the sudoku rules after an earlier placement in the round is skipped.
+    pub fn fill_obvious(&mut self) -> bool {
+        let singles = self
+            .find_single_candidates()
+            .into_iter()
+            .chain(self.find_hidden_singles())
+            .collect::<Vec<(Position, Field)>>();
+
+        let mut changed = false;
+
+        for (position, field) in singles {
+            if self.get_field(position).is_empty()
+                && self.valid_number_at_position(position, &field)
+            {
+                self.put_field(position, field);
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Copy the filled fields of another `Board` into the empty fields of this `Board`,
+    /// for instance to apply hints or a partial solution. Fields already filled are left
+    /// untouched, and fields that would break the sudoku rules are skipped. Returns the
+    /// number of fields copied.
+    pub fn fill_from(&mut self, other: &Board) -> usize {
+        let mut filled = 0;
+
+        for position in PositionIter::from_first_field() {
+            let field = other.get_field(position);
+
+            if field.is_filled()
+                && self.is_empty_at(position)
+                && self.valid_number_at_position(position, field)
+            {
+                self.put_field(position, *field);
+                filled += 1;
+            }
+        }
+
+        filled
+    }
+
+    /// Get a copy of the `Board` with a digit 1-9 placed at a `Position`, leaving the
+    /// `Board` itself untouched. Any digit already placed there by solving is replaced,
+    /// and the placed digit is not a given.
+    ///
+    /// Fails with `InvalidCharacter` if the digit is not 1-9, and with
+    /// `SudokuRuleViolation` if the position holds a given or the digit is already used
+    /// in the row, column or box.
+    pub fn with_field(self, position: Position, digit: u8) -> Result<Board, FieldParseError> {
+        let field = Field::new(digit)?;
+
+        if self.is_given(position) {
+            return Err(FieldParseError::SudokuRuleViolation);
+        }
+
+        let mut board = self;
+        board.put_field(position, Field::empty());
+
+        if !board.valid_number_at_position(position, &field) {
+            return Err(FieldParseError::SudokuRuleViolation);
+        }
+
+        board.put_field(position, field);
+        Ok(board)
+    }
+
+    /// Rate how hard the `Board` is for a human to solve, by trying ever harder logical
+    /// techniques until the board is solved. Boards that can't be solved by logic alone
+    /// are `Expert`, which includes boards without a unique solution.
+    pub fn difficulty(&self) -> Difficulty {
+        let mut candidate_grid = CandidateGrid::from_board(self);
+
+        for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            loop {
+                if candidate_grid.place_singles()
+                    || (difficulty >= Difficulty::Medium && candidate_grid.box_line_reduction() > 0)
+                    || (difficulty >= Difficulty::Hard && candidate_grid.eliminate_pairs() > 0)
+                {
+                    continue;
+                }
+
+                break;
+            }
+
+            if candidate_grid.board().empty_positions().next().is_none() {
+                return difficulty;
+            }
+        }
+
+        Difficulty::Expert
+    }
+
+    /// Can the `Board` be solved by logic alone, without guessing? This applies the same
+    /// techniques as `difficulty`: singles, box/line reduction and pairs.
+    pub fn is_logically_solvable(self) -> bool {
+        self.difficulty() != Difficulty::Expert
+    }
+
+    /// Cheap estimate of how hard the `Board` is to solve, without solving it.
+    ///
+    /// The estimate is the sum of the candidate count minus one for each empty field,
+    /// which is a proxy for the branching factor of the search. A higher number means
+    /// that more guessing is likely. A solved board has an estimate of 0.
+    pub fn difficulty_estimate(&self) -> usize {
+        self.empty_positions()
+            .map(|position| self.candidates(position).len().saturating_sub(1))
+            .sum()
+    }
+
+    /////////////////
+    // Transforms //
+    ///////////////
+
+    /// Get a copy of the `Board` with two of the three bands swapped, where a band is
+    /// three rows of boxes and band 0 is at the top. The copy is a valid sudoku with the
+    /// same number of solutions. Fails with `InvalidIndex` if a band is not 0-2.
+    pub fn swap_bands(&self, a: usize, b: usize) -> Result<Board, SudokuTransformError> {
+        if a >= 3 || b >= 3 {
+            return Err(SudokuTransformError::InvalidIndex);
+        }
+
+        Ok(self.rearranged(|position| Position {
+            row: swapped_block(position.row, a, b),
+            column: position.column,
+        }))
+    }
+
+    /// Get a copy of the `Board` with two of the three stacks swapped, where a stack is
+    /// three columns of boxes and stack 0 is to the left. The copy is a valid sudoku with
+    /// the same number of solutions. Fails with `InvalidIndex` if a stack is not 0-2.
+    pub fn swap_stacks(&self, a: usize, b: usize) -> Result<Board, SudokuTransformError> {
+        if a >= 3 || b >= 3 {
+            return Err(SudokuTransformError::InvalidIndex);
+        }
+
+        Ok(self.rearranged(|position| Position {
+            row: position.row,
+            column: swapped_block(position.column, a, b),
+        }))
+    }
+
+    /// Get a copy of the `Board` with two rows in the same band swapped. The copy is a
+    /// valid sudoku with the same number of solutions. Fails with `InvalidIndex` if a row
+    /// is not 0-8, and with `NotInSameBand` if the rows are in different bands, since
+    /// that could break the sudoku rules.
+    pub fn swap_rows(&self, a: usize, b: usize) -> Result<Board, SudokuTransformError> {
+        if a >= 9 || b >= 9 {
+            return Err(SudokuTransformError::InvalidIndex);
+        }
+
+        if a / 3 != b / 3 {
+            return Err(SudokuTransformError::NotInSameBand);
+        }
+
+        Ok(self.rearranged(|position| Position {
+            row: swapped_index(position.row, a, b),
+            column: position.column,
+        }))
+    }
+
+    /// Get a copy of the `Board` with two columns in the same stack swapped. The copy is
+    /// a valid sudoku with the same number of solutions. Fails with `InvalidIndex` if a
+    /// column is not 0-8, and with `NotInSameStack` if the columns are in different
+    /// stacks, since that could break the sudoku rules.
+    pub fn swap_columns(&self, a: usize, b: usize) -> Result<Board, SudokuTransformError> {
+        if a >= 9 || b >= 9 {
+            return Err(SudokuTransformError::InvalidIndex);
+        }
+
+        if a / 3 != b / 3 {
+            return Err(SudokuTransformError::NotInSameStack);
+        }
+
+        Ok(self.rearranged(|position| Position {
+            row: position.row,
+            column: swapped_index(position.column, a, b),
+        }))
+    }
+
+    /// Get the canonical form of the `Board`, which is the same for every board that is
+    /// equivalent to it. Useful for finding duplicates in a collection of puzzles.
+    ///
+    /// Two boards are equivalent when one can be turned into the other by any
+    /// combination of:
+    ///
+    /// - Permuting the three bands
+    /// - Permuting the rows within each band
+    /// - Permuting the three stacks
+    /// - Permuting the columns within each stack
+    /// - Transposing the board, which combined with the above gives all rotations and
+    ///   reflections
+    /// - Relabeling the digits
+    ///
+    /// The canonical form is the equivalent board that is lexicographically smallest when
+    /// read in row-major order with 0 for empty fields. All filled fields of the canonical
+    /// form are givens.
+    ///
+    /// This tries all 3,359,232 rearrangements of the fields, so it is an expensive operation.
+    pub fn canonical(&self) -> Board {
+        let grid = self.to_grid();
+        let mut transposed = [[0; 9]; 9];
+
+        for (row, values) in grid.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                transposed[column][row] = *value;
+            }
+        }
+
+        let orders = block_preserving_orders();
+        let mut smallest = [u8::MAX; 81];
+
+        for grid in [grid, transposed] {
+            for rows in &orders {
+                for columns in &orders {
+                    relabel_if_smaller(&grid, rows, columns, &mut smallest);
+                }
+            }
+        }
+
+        let mut canonical = Board::empty();
+
+        for (value, position) in smallest.iter().zip(PositionIter::from_first_field()) {
+            if *value != 0 {
+                canonical.put_field(position, Field::from_u8(*value));
+            }
+        }
+
+        canonical.with_filled_fields_as_givens()
+    }
+
+    /// Generate a random sudoku with a unique solution and `target_clues` clues. The same
+    /// seed always gives the same sudoku.
+    ///
+    /// Clues are removed from a random solved board in a random order while the solution
+    /// stays unique. Few random sudokus can get below 20 clues this way, so when no more
+    /// clues can be removed, the sudoku may have more clues than the target. Fails with
+    /// `TooFewClues` if the target is below `Board::MIN_CLUES`, as no such sudoku exists.
+    pub fn generate(seed: u64, target_clues: usize) -> Result<Board, SudokuGenerateError> {
+        if target_clues < Board::MIN_CLUES {
+            return Err(SudokuGenerateError::TooFewClues);
+        }
+
+        let mut rng = Rng::new(seed);
+        let mut board = Board::random_solution(&mut rng);
+
+        let mut positions = PositionIter::from_first_field().collect::<Vec<Position>>();
+        shuffle(&mut positions, &mut rng);
+
+        let mut clues = positions.len();
+
+        for position in positions {
+            if clues <= target_clues {
+                break;
+            }
+
+            let mut reduced_board = board;
+            reduced_board.put_field(position, Field::empty());
+
+            if reduced_board.has_unique_solution() {
+                board = reduced_board;
+                clues -= 1;
+            }
+        }
+
+        Ok(board.with_filled_fields_as_givens())
+    }
+
+    /// Generate a random minimal sudoku with a unique solution, aiming for a `Difficulty`.
+    /// The same seed always gives the same sudoku.
+    ///
+    /// Random minimal sudokus are generated and rated until one has the target difficulty.
+    /// Most generated sudokus are easy, so harder targets take more attempts, and `Expert`
+    /// ones are rare. After `Board::GENERATE_ATTEMPTS` attempts the sudoku closest to the
+    /// target is returned instead.
+    pub fn generate_difficulty(seed: u64, target: Difficulty) -> Board {
+        let mut rng = Rng::new(seed);
+        let distance = |difficulty: Difficulty| (difficulty as u8).abs_diff(target as u8);
+
+        let mut closest = Board::random_minimal(&mut rng);
+        let mut closest_difficulty = closest.difficulty();
+
+        for _ in 1..Board::GENERATE_ATTEMPTS {
+            if closest_difficulty == target {
+                break;
+            }
+
+            let board = Board::random_minimal(&mut rng);
+            let difficulty = board.difficulty();
+
+            if distance(difficulty) < distance(closest_difficulty) {
+                closest = board;
+                closest_difficulty = difficulty;
+            }
+        }
+
+        closest
+    }
+
+    /// Generate a random minimal sudoku by minimizing a random solved board
+    fn random_minimal(rng: &mut Rng) -> Board {
+        Board::random_solution(rng).minimize(rng.next_u64())
+    }
+
+    /// Generate a random solved board
+    fn random_solution(rng: &mut Rng) -> Board {
+        let mut board = Board::empty();
+
+        // The diagonal boxes don't share any rows or columns, so they can be filled with
+        // any digits and the board is still solvable
+        for index in [0, 4, 8] {
+            let mut digits = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+            shuffle(&mut digits, rng);
+
+            for (position, digit) in Unit::Box(index).positions().into_iter().zip(digits) {
+                board.put_field(position, Field::from_u8(digit));
+            }
+        }
+
+        board.solve_fast().unwrap_or(board)
+    }
+
+    /// Get a random puzzle equivalent to the `Board`, by permuting the bands, the rows
+    /// within each band, the stacks and the columns within each stack, possibly
+    /// transposing, and relabeling the digits. The same seed always gives the same puzzle.
+    ///
+    /// The result looks different, but has the same number of solutions and the same
+    /// canonical form as the `Board`.
+    pub fn shuffle_equivalent(&self, seed: u64) -> Board {
+        let mut rng = Rng::new(seed);
+        let rows = random_block_preserving_order(&mut rng);
+        let columns = random_block_preserving_order(&mut rng);
+        let transpose = rng.below(2) == 1;
+
+        let mut labels = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        shuffle(&mut labels, &mut rng);
+
+        self.rearranged(|position| {
+            let (row, column) = if transpose {
+                (columns[position.column], rows[position.row])
+            } else {
+                (rows[position.row], columns[position.column])
+            };
+
+            Position { row, column }
+        })
+        .relabeled(labels)
+    }
+
+    /// Get the complement of the `Board`, where every digit d is replaced by 10 - d, so
+    /// 1 and 9 swap places, 2 and 8 swap places and so on. The complement looks different,
+    /// but is a valid sudoku with the same givens and number of solutions.
+    pub fn complement(&self) -> Board {
+        self.relabeled([9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+
+    /// Get a copy of the `Board` where every digit d is replaced by `labels[d - 1]`. The
+    /// labels must be a permutation of 1-9 to keep the board valid.
+    fn relabeled(&self, labels: [u8; 9]) -> Board {
+        let mut board = *self;
+
+        for position in PositionIter::from_first_field() {
+            if let Some(value) = board.get_field(position).value() {
+                board.put_field(position, Field::from_u8(labels[value as usize - 1]));
+            }
+        }
+
+        board
+    }
+
+    /// Get a copy of the `Board` where the field and given at each `Position` is taken
+    /// from the `Position` returned by `source_of`. The caller must make sure the
+    /// rearrangement keeps the board valid.
+    fn rearranged(&self, source_of: impl Fn(Position) -> Position) -> Board {
+        let mut board = Board::empty();
+
+        for position in PositionIter::from_first_field() {
+            let source = source_of(position);
+            board.put_field(position, *self.get_field(source));
+
+            if self.is_given(source) {
+                board.givens |= 1 << position.to_index();
+            }
+        }
+
+        board
+    }
+
+    //////////////////
+    // Conversions //
+    ////////////////
+
+    /// Create a `Board` from its clues, given as `Position`s and digits 1-9. All other
+    /// fields are empty.
+    ///
+    /// The clues are validated like a parsed board, so digits outside 1-9 give
+    /// `InvalidCharacter` errors and clashing clues give `SudokuRuleViolation` errors at
+    /// their positions. Two clues for the same position also clash.
+    pub fn from_clues(
+        clues: impl IntoIterator<Item = (Position, u8)>,
+    ) -> Result<Board, SudokuParseError> {
+        let mut fields = [None; 81];
+        let mut duplicates = HashSet::new();
+
+        for (position, digit) in clues {
+            if fields[position.to_index()].replace(digit).is_some() {
+                duplicates.insert((position, FieldParseError::SudokuRuleViolation));
+            }
+        }
+
+        match Board::parse_fields(&fields) {
+            Ok(board) if duplicates.is_empty() => Ok(board),
+            Ok(_) => Err(SudokuParseError::ParseErrors(duplicates)),
+            Err(SudokuParseError::ParseErrors(errors)) => Err(SudokuParseError::ParseErrors(
+                errors.union(&duplicates).cloned().collect(),
+            )),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Get the `Board` as 81 ASCII bytes in row-major order. Filled fields are their
+    /// digit and empty fields are `b'0'`.
+    ///
+    /// Parsing the bytes again with `Board::try_from` gives back the same `Board`.
+    pub fn as_bytes(&self) -> [u8; 81] {
+        let mut bytes = [b'0'; 81];
+
+        for (byte, position) in bytes.iter_mut().zip(PositionIter::from_first_field()) {
+            if let Some(value) = self.get_field(position).value() {
+                *byte = b'0' + value;
+            }
+        }
+
+        bytes
+    }
+
+    /// Get a 64-bit id of the fields of the `Board`, for deduplicating and caching boards
+    /// outside the program. Equal boards have the same id, and the id is stable across
+    /// runs and versions of fabrik. Different boards can share an id, but it is unlikely.
+    ///
+    /// The id is the 64-bit FNV-1a hash of `as_bytes`.
+    pub fn board_id(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        self.as_bytes().iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    /// Get the `Board` as a 2D array of rows. Filled fields are their digit and empty
+    /// fields are 0.
+    ///
+    /// Creating a `Board` from the array with `Board::try_from` gives back the same `Board`.
+    pub fn to_grid(&self) -> [[u8; 9]; 9] {
+        self.fields
+            .map(|row| row.map(|field| field.value().unwrap_or(0)))
+    }
+
+    /// Render the `Board` like `Display`, but with a chosen character for empty fields,
+    /// and optionally without the borders. Without borders each row is a line of nine
+    /// characters.
+    pub fn display_with(&self, empty: char, borders: bool) -> String {
+        let grid = self.to_char_grid(empty);
+        let mut output = String::new();
+
+        if borders {
+            // Writing to a String can't fail
+            let _ = write_grid(&mut output, |position| grid[position.row][position.column]);
+        } else {
+            for row in grid {
+                output.extend(row);
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    /// Does the `Board` have the same fields as a sudoku string, in any format accepted
+    /// by `Board::try_from`? Strings that don't parse never match.
+    pub fn matches_line(&self, line: &str) -> bool {
+        Board::try_from(line).is_ok_and(|board| board == *self)
+    }
+
+    /// Get the `Board` as a 2D array of rows, where filled fields are `Some` digit and
+    /// empty fields are `None`.
+    ///
+    /// Creating a `Board` from the flattened rows with `Board::try_from` gives back the
+    /// same `Board`.
+    pub fn to_option_grid(&self) -> [[Option<u8>; 9]; 9] {
+        self.fields.map(|row| row.map(|field| field.value()))
+    }
+
+    /// Get the `Board` as a 2D array of rows of characters, for custom renderers. Filled
+    /// fields are their digit and empty fields are the `empty` character.
+    pub fn to_char_grid(&self, empty: char) -> [[char; 9]; 9] {
+        self.fields.map(|row| {
+            row.map(|field| {
+                if field.is_empty() {
+                    empty
+                } else {
+                    field.to_char()
+                }
+            })
+        })
+    }
+
+    /////////////////
+    // Validation //
+    ///////////////
+
+    /// Check that no fields on the `Board` violate the sudoku rules, returning the same
+    /// errors as parsing would. Boards created by fabrik always validate.
+    pub fn validate(&self) -> Result<(), SudokuParseError> {
+        let rule_violations = self
+            .rule_violations()
+            .into_iter()
+            .map(|pos| (pos, FieldParseError::SudokuRuleViolation))
+            .collect::<HashSet<(Position, FieldParseError)>>();
+
+        if rule_violations.is_empty() {
+            Ok(())
+        } else {
+            Err(SudokuParseError::ParseErrors(rule_violations))
+        }
+    }
+
+    /// Is every field on the `Board` filled without breaking the sudoku rules? Boards
+    /// created by fabrik never break the rules, so for them this is the same as checking
+    /// that the board is solved.
+    pub fn is_complete_and_valid(&self) -> bool {
+        self.empty_positions().next().is_none() && self.validate().is_ok()
+    }
+
+    /// Is a candidate `Board` a correct solution of this puzzle? That is the case when the
+    /// candidate is complete and valid, and has the same digits in all the filled fields
+    /// of the puzzle.
+    pub fn is_solution(&self, candidate: &Board) -> bool {
+        candidate.is_complete_and_valid()
+            && PositionIter::from_first_field().all(|position| {
+                self.is_empty_at(position)
+                    || self.get_field(position) == candidate.get_field(position)
+            })
+    }
+
+    /// Check if a 2D array of rows, with 0 for empty fields, would make a valid `Board`.
+    /// All values must be 0-9, and no digit may appear twice in a row, column or box.
+    ///
+    /// This is faster than `Board::try_from` when the errors are not needed.
+    pub fn is_valid_grid(grid: &[[u8; 9]; 9]) -> bool {
+        // Bitmasks of the digits seen in each row, column and box
+        let mut rows = [0u16; 9];
+        let mut columns = [0u16; 9];
+        let mut boxes = [0u16; 9];
+
+        for (row, values) in grid.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                match value {
+                    0 => continue,
+                    1..=9 => {}
+                    _ => return false,
+                }
+
+                let bit = 1 << value;
+                let square = row / 3 * 3 + column / 3;
+
+                if (rows[row] | columns[column] | boxes[square]) & bit != 0 {
+                    return false;
+                }
+
+                rows[row] |= bit;
+                columns[column] |= bit;
+                boxes[square] |= bit;
+            }
+        }
+
+        true
+    }
+
+    /// Get every `Conflict` on the `Board`, which is every digit appearing more than once
+    /// in a row, column or box, in row-major order. A field in more than one kind of unit
+    /// with the same digit gives a conflict for each kind. Boards created by fabrik never
+    /// have conflicts.
+    pub fn conflict_details(&self) -> Vec<Conflict> {
+        PositionIter::from_first_field()
+            .map(|position| (position, *self.get_field(position)))
+            .filter(|(_, field)| field.is_filled())
+            .flat_map(|(position, field)| {
+                let mut temp_board = *self;
+                temp_board.put_field(position, Field::empty());
+
+                [
+                    (
+                        ConflictKind::Row,
+                        temp_board.number_used_in_row(position, &field),
+                    ),
+                    (
+                        ConflictKind::Column,
+                        temp_board.number_used_in_column(position, &field),
+                    ),
+                    (
+                        ConflictKind::Box,
+                        temp_board.number_used_in_square(position, &field),
+                    ),
+                ]
+                .into_iter()
+                .filter(|(_, is_used)| *is_used)
+                .map(move |(kind, _)| Conflict {
+                    position,
+                    field,
+                    kind,
+                })
+            })
+            .collect()
+    }
+
+    /// Do any digits in the `Board` violate the sudoku rules? For instance it is not
+    /// valid to have the digit 5 twice in a row on the board.
+    fn rule_violations(self) -> HashSet<Position> {
+        self.conflict_details()
+            .iter()
+            .map(|conflict| conflict.position())
+            .collect::<HashSet<Position>>()
+    }
+
+    /// Find an empty field without any candidates. Such a field can never be filled,
+    /// so the board is unsolvable without searching.
+    pub(crate) fn find_contradiction(&self) -> Option<Position> {
+        self.empty_positions()
+            .find(|position| self.candidates(*position).is_empty())
+    }
+
+    /// Is a number valid at a given position?
+    /// Note: This assumes the field is not in the board yet
+    pub(crate) fn valid_number_at_position(&self, position: Position, number: &Field) -> bool {
+        !self.number_used_in_row(position, number)
+            && !self.number_used_in_column(position, number)
+            && !self.number_used_in_square(position, number)
+    }
+
+    /// Is a number currently used in a row?
+    fn number_used_in_row(&self, position: Position, number: &Field) -> bool {
+        let row_slice = &self.fields[position.row];
+        row_slice.iter().any(|field| field == number)
+    }
+
+    /// Is a number currently used in a column?
+    fn number_used_in_column(&self, position: Position, number: &Field) -> bool {
+        (0..9)
+            .map(|row| Position {
+                row,
+                column: position.column,
+            })
+            .any(|position| self.get_field(position) == number)
+    }
+
+    /// Is a number used in a 3x3 square?
+    fn number_used_in_square(&self, position: Position, number: &Field) -> bool {
+        let origin = position.box_origin();
+
+        (0..3)
+            .map(|row_increase| {
+                &self.fields[origin.row + row_increase][origin.column..(origin.column + 3)]
+            })
+            .any(|slice| slice.contains(number))
+    }
+}
+
+/// Create a `Board`  from a `String`
+impl TryFrom<String> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Board::try_from(input.as_str())
+    }
+}
+
+/// Create a `Board` from a `str`
+///
+/// The digits 1-9 are clues, and `-`, `.` and `0` are empty fields. Whitespace
+/// is ignored, so the rows can be split over several lines.
+impl TryFrom<&str> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let input_vector = input
+            .split_whitespace()
+            .collect::<String>()
+            .bytes()
+            .collect::<Vec<u8>>();
+
+        Board::try_from(input_vector)
+    }
+}
+
+/// Create a `Board` from a vector of bytes
+impl TryFrom<Vec<u8>> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: Vec<u8>) -> Result<Self, Self::Error> {
+        let prepared_vec = input.iter().map(parse_byte).collect::<Vec<Option<u8>>>();
+
+        Board::try_from(prepared_vec)
+    }
+}
+
+/// Create a `Board` from a fixed size array of bytes without allocating an intermediate vector
+impl TryFrom<&[u8; 81]> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: &[u8; 81]) -> Result<Self, Self::Error> {
+        Board::parse_fields(&input.map(|byte| parse_byte(&byte)))
+    }
+}
+
+/// Create a `Board` from a fixed size array of bytes
+impl TryFrom<[u8; 81]> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: [u8; 81]) -> Result<Self, Self::Error> {
+        Board::try_from(&input)
+    }
+}
+
+/// Create a `Board` from a vector of `Option<u8>` where the u8 is a Some with digit 1-9
+/// and empty fields are represented as None
+impl TryFrom<Vec<Option<u8>>> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: Vec<Option<u8>>) -> Result<Self, Self::Error> {
+        Board::parse_fields(&input)
+    }
+}
+
+/// Create a `Board` from a 2D array of rows, where each value is a digit 1-9 and empty
+/// fields are 0
+impl TryFrom<[[u8; 9]; 9]> for Board {
+    type Error = SudokuParseError;
+
+    fn try_from(input: [[u8; 9]; 9]) -> Result<Self, Self::Error> {
+        let fields = input
+            .iter()
+            .flatten()
+            .map(|value| match value {
+                0 => None,
+                value => Some(*value),
+            })
+            .collect::<Vec<Option<u8>>>();
+
+        Board::parse_fields(&fields)
+    }
+}
+
+/// Map a row or column index to the index it is moved to when swapping `a` and `b`
+fn swapped_index(index: usize, a: usize, b: usize) -> usize {
+    match index {
+        index if index == a => b,
+        index if index == b => a,
+        _ => index,
+    }
+}
+
+/// Map a row or column index to the index it is moved to when swapping the blocks of
+/// three rows or columns `a` and `b`
+fn swapped_block(index: usize, a: usize, b: usize) -> usize {
+    match index / 3 {
+        block if block == a => b * 3 + index % 3,
+        block if block == b => a * 3 + index % 3,
+        _ => index,
+    }
+}
+
+/// Get all 1296 orders of the nine rows or columns that keep them in blocks of three,
+/// i.e. the orders possible with band and row swaps
+fn block_preserving_orders() -> Vec<[usize; 9]> {
+    const PERMUTATIONS: [[usize; 3]; 6] = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+
+    let mut orders = Vec::with_capacity(1296);
+
+    for blocks in PERMUTATIONS {
+        for first in PERMUTATIONS {
+            for second in PERMUTATIONS {
+                for third in PERMUTATIONS {
+                    let within_blocks = [first, second, third];
+                    let mut order = [0; 9];
+
+                    for (index, item) in order.iter_mut().enumerate() {
+                        *item = blocks[index / 3] * 3 + within_blocks[index / 3][index % 3];
+                    }
+
+                    orders.push(order);
+                }
+            }
+        }
+    }
+
+    orders
+}
+
+/// Get a random order of the nine rows or columns that keeps them in blocks of three
+fn random_block_preserving_order(rng: &mut Rng) -> [usize; 9] {
+    let mut blocks = [0, 1, 2];
+    shuffle(&mut blocks, rng);
+
+    let mut order = [0; 9];
+
+    for (block_index, block) in blocks.iter().enumerate() {
+        let mut within_block = [0, 1, 2];
+        shuffle(&mut within_block, rng);
+
+        for (index, offset) in within_block.iter().enumerate() {
+            order[block_index * 3 + index] = block * 3 + offset;
+        }
+    }
+
+    order
+}
+
+/// Read the grid with its rows and columns in the given orders, relabeling the digits
+/// in order of appearance. If the result is lexicographically smaller than `smallest`,
+/// it replaces it.
+fn relabel_if_smaller(
+    grid: &[[u8; 9]; 9],
+    rows: &[usize; 9],
+    columns: &[usize; 9],
+    smallest: &mut [u8; 81],
+) {
+    let mut labels = [0; 10];
+    let mut next_label = 1;
+    let mut is_smaller = false;
+
+    for (index, smallest_value) in smallest.iter_mut().enumerate() {
+        let value = grid[rows[index / 9]][columns[index % 9]] as usize;
+
+        let label = if value == 0 {
+            0
+        } else {
+            if labels[value] == 0 {
+                labels[value] = next_label;
+                next_label += 1;
+            }
+
+            labels[value]
+        };
+
+        // Give up as soon as the result is known to be larger, which is usually early
+        if !is_smaller {
+            match label.cmp(smallest_value) {
+                std::cmp::Ordering::Greater => return,
+                std::cmp::Ordering::Less => is_smaller = true,
+                std::cmp::Ordering::Equal => {}
+            }
+        }
+
+        *smallest_value = label;
+    }
+}
+
+/// Map an ASCII byte to a digit 1-9, or None for the empty field markers `-`, `.`, `0` and space
+fn parse_byte(byte: &u8) -> Option<u8> {
+    match byte {
+        b'-' | b'.' | b'0' | b' ' => None,
+        val if (49..=57).contains(val) => Some(val - 48),
+        _ => Some(255), // Use an invalid field value which will fail in the next step
+    }
+}
+
+impl Board {
+    /// Parse a slice of `Option<u8>` into a `Board`, collecting all invalid fields
+    /// and rule violations as errors
+    fn parse_fields(input: &[Option<u8>]) -> Result<Board, SudokuParseError> {
+        if input.len() != 81 {
+            return Err(SudokuParseError::InvalidLength);
+        }
+
+        // 1. Build up a board, treating invalid fields as empty fields,
+        //    while inserting them as validation errors in the HashSet.
+        let mut lenient_board = Board::empty();
+        let mut positions_with_parse_errors = HashSet::new();
+
+        for (field, position) in input.iter().zip(PositionIter::from_first_field()) {
+            let parsed_field = match field {
+                Some(val) => match Field::new(*val) {
+                    Ok(field) => field,
+                    Err(_) => {
+                        positions_with_parse_errors
+                            .insert((position, FieldParseError::InvalidCharacter));
+                        Field::empty()
+                    }
+                },
+                None => Field::empty(),
+            };
+
+            lenient_board.put_field(position, parsed_field);
+        }
+
+        // 2. Check the leniently parsed board for rule violations
+        let rule_violations = lenient_board
+            .rule_violations()
+            .iter()
+            .map(|pos| (*pos, FieldParseError::SudokuRuleViolation))
+            .collect::<HashSet<(Position, FieldParseError)>>();
+
+        let all_errors = positions_with_parse_errors
+            .union(&rule_violations)
+            .cloned()
+            .collect::<HashSet<(Position, FieldParseError)>>();
+
+        // If no errors, the board is valid and the filled fields are its givens
+        if all_errors.is_empty() {
+            Ok(lenient_board.with_filled_fields_as_givens())
+        } else {
+            Err(SudokuParseError::ParseErrors(all_errors))
+        }
+    }
+}
+
+/// Get a `String` representation of a `Board`
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_grid(f, |position| self.get_field(position).to_char())
+    }
+}
+
+/// Write a bordered 9x9 grid, asking `char_at` for the character to put in each field
+pub(crate) fn write_grid(
+    f: &mut impl std::fmt::Write,
+    char_at: impl Fn(Position) -> char,
+) -> std::fmt::Result {
+    writeln!(f, "+-----------+")?;
+
+    for row in 0..=8 {
+        write!(f, "|")?;
+
+        for column in 0..=8 {
+            write!(f, "{}", char_at(Position { row, column }))?;
+
+            if (column + 1) % 3 == 0 {
+                write!(f, "|")?;
+            }
+        }
+
+        writeln!(f)?;
+
+        if (row + 1) % 3 == 0 && row != 8 {
+            writeln!(f, "+---+---+---+")?;
+        }
+    }
+
+    writeln!(f, "+-----------+")?;
+
+    Ok(())
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod accessor_tests {
+    use super::*;
+
+    #[test]
+    fn gets_a_field() {
+        let board = Board::try_from(
+            "1--------
+             -2-------
+             --3------
+             ---4-----
+             ----5----
+             -----6---
+             ------7--
+             -------8-
+             --------9
+        ",
+        )
+        .unwrap();
+
+        assert_eq!(
+            board.get_field(Position { row: 0, column: 0 }),
+            &Field::from_u8(1)
+        );
+
+        assert_eq!(
+            board.get_field(Position { row: 8, column: 8 }),
+            &Field::from_u8(9)
+        );
+
+        assert_eq!(
+            board.get_field(Position { row: 8, column: 7 }),
+            &Field::empty()
+        );
+    }
+
+    #[test]
+    fn grades_a_board_against_its_solution() {
+        let mut board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+
+        // The solution has 1 at the first empty field and 3 at the second
+        board.put_field(Position { row: 0, column: 1 }, Field::from_u8(1));
+        board.put_field(Position { row: 0, column: 2 }, Field::from_u8(8));
+
+        let grade = board.grade_against(&solution);
+
+        assert_eq!(grade.correct(), 32);
+        assert_eq!(grade.incorrect(), 1);
+        assert_eq!(
+            grade.incorrect_positions(),
+            &[Position { row: 0, column: 2 }]
+        );
+        assert_eq!(grade.empty(), 48);
+        assert!(!grade.is_solved());
+
+        assert!(solution.grade_against(&solution).is_solved());
+    }
+
+    #[test]
+    fn checks_if_a_board_is_a_relabeling() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let relabeled = Board::try_from(board.to_grid().map(|row| {
+            row.map(|value| match value {
+                0 => 0,
+                value => value % 9 + 1,
+            })
+        }))
+        .unwrap();
+
+        assert!(board.is_relabeling_of(&board));
+        assert!(board.is_relabeling_of(&relabeled));
+        assert!(relabeled.is_relabeling_of(&board));
+        assert_ne!(board, relabeled);
+
+        // Same digits in different places
+        assert!(!board.is_relabeling_of(&board.swap_rows(0, 1).unwrap()));
+
+        // Same empty fields, but two digits map to the same digit
+        let mut merged = relabeled;
+        merged.put_field(Position { row: 0, column: 0 }, Field::from_u8(6));
+        assert!(!board.is_relabeling_of(&merged));
+        assert!(!merged.is_relabeling_of(&board));
+    }
+
+    #[test]
+    fn checks_if_a_field_is_filled_or_empty() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let filled = Position { row: 0, column: 0 };
+        let empty = Position { row: 0, column: 1 };
+
+        assert!(board.is_filled_at(filled));
+        assert!(!board.is_empty_at(filled));
+        assert!(board.is_empty_at(empty));
+        assert!(!board.is_filled_at(empty));
+    }
+
+    #[test]
+    fn lists_the_clues_in_row_major_order() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let clues = board.clues();
+
+        assert_eq!(clues.len(), 31);
+        assert_eq!(
+            clues[..3],
+            [
+                (Position { row: 0, column: 0 }, Field::from_u8(6)),
+                (Position { row: 0, column: 8 }, Field::from_u8(4)),
+                (Position { row: 1, column: 1 }, Field::from_u8(4)),
+            ]
+        );
+
+        let round_tripped = Board::from_clues(
+            clues
+                .into_iter()
+                .filter_map(|(position, field)| Some((position, field.value()?))),
+        );
+
+        assert_eq!(round_tripped, Ok(board));
+        assert!(Board::empty().clues().is_empty());
+    }
+
+    #[test]
+    fn counts_clues_by_digit() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+
+        assert_eq!(board.count_clues_by_digit(), [2, 5, 4, 4, 6, 4, 1, 3, 2]);
+        assert_eq!(
+            board.first_solution().unwrap().count_clues_by_digit(),
+            board.count_clues_by_digit()
+        );
+        assert_eq!(Board::empty().count_clues_by_digit(), [0; 9]);
+    }
+
+    #[test]
+    fn counts_clues_per_unit() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+
+        assert_eq!(board.clues_per_box(), [5, 1, 5, 2, 5, 2, 5, 1, 5]);
+        assert_eq!(board.clues_per_row(), [2, 5, 4, 2, 5, 2, 5, 4, 2]);
+        assert_eq!(board.clues_per_column(), [3, 5, 4, 2, 3, 2, 4, 5, 3]);
+
+        // Fields filled by solving are not clues
+        assert_eq!(
+            board.first_solution().unwrap().clues_per_box(),
+            board.clues_per_box()
+        );
+        assert_eq!(Board::empty().clues_per_row(), [0; 9]);
+    }
+
+    #[test]
+    fn iterates_boxes_in_reading_order() {
+        let board = Board::try_from(
+            "1--------
+             -2-------
+             --3------
+             ---4-----
+             ----5----
+             -----6---
+             ------7--
+             -------8-
+             --------9
+        ",
+        )
+        .unwrap();
+
+        let boxes = board.boxes().collect::<Vec<[Field; 9]>>();
+        let empty = Field::empty();
+
+        assert_eq!(boxes.len(), 9);
+        assert_eq!(
+            boxes[0],
+            [
+                Field::from_u8(1),
+                empty,
+                empty,
+                empty,
+                Field::from_u8(2),
+                empty,
+                empty,
+                empty,
+                Field::from_u8(3)
+            ]
+        );
+        assert_eq!(boxes[1], [empty; 9]);
+        assert_eq!(boxes[8][8], Field::from_u8(9));
+    }
+
+    #[test]
+    fn puts_a_field() {
+        let mut board = Board::empty();
+
+        assert_eq!(
+            board.get_field(Position { row: 1, column: 3 }),
+            &Field::empty()
+        );
+
+        board.put_field(Position { row: 1, column: 3 }, Field::from_u8(2));
+
+        assert_eq!(
+            board.get_field(Position { row: 1, column: 3 }),
+            &Field::from_u8(2)
+        );
+    }
+
+    #[test]
+    fn returns_next_empty_field() {
+        // The board is "sudokus/starry.txt" solved except for one field
+        let mut board = Board::try_from(
+            "613529784
+             742836519
+             985174326
+             269385147
+             53194-268
+             874612935
+             426751893
+             397268451
+             158493672",
+        )
+        .unwrap();
+
+        assert_eq!(
+            board.next_empty_field(Position { row: 0, column: 4 }),
+            Some(Position { row: 4, column: 5 })
+        );
+
+        board.put_field(Position { row: 4, column: 5 }, Field::from_u8(7));
+
+        assert!(board
+            .next_empty_field(Position { row: 0, column: 4 })
+            .is_none());
+    }
+
+    #[test]
+    fn remembers_the_givens() {
+        let board = Board::try_from(
+            "1--------
+             -2-------
+             --3------
+             ---4-----
+             ----5----
+             -----6---
+             ------7--
+             -------8-
+             --------9",
+        )
+        .unwrap();
+
+        let expected_givens = (0..9)
+            .map(|i| Position { row: i, column: i })
+            .collect::<HashSet<Position>>();
+
+        let solution = board.first_solution().unwrap();
+
+        assert_eq!(board.givens(), expected_givens);
+        assert_eq!(solution.givens(), expected_givens);
+        assert!(solution.is_given(Position { row: 4, column: 4 }));
+        assert!(!solution.is_given(Position { row: 4, column: 5 }));
+
+        // Boards are equal regardless of their givens
+        let parsed_solution = Board::try_from(&solution.as_bytes()).unwrap();
+        assert_eq!(parsed_solution.givens().len(), 81);
+        assert_eq!(parsed_solution, solution);
+    }
+
+    #[test]
+    fn calculates_solved_percentage() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+
+        assert_eq!(board.solved_percentage(), 28.0 / 81.0 * 100.0);
+        assert_eq!(board.first_solution().unwrap().solved_percentage(), 100.0);
+    }
+
+    #[test]
+    fn diffs_two_boards() {
+        let board = Board::try_from(
+            "1--------
+             -2-------
+             --3------
+             ---4-----
+             ----5----
+             -----6---
+             ------7--
+             -------8-
+             --------9",
+        )
+        .unwrap();
+
+        let other = Board::try_from(
+            "1--------
+             -2-------
+             --3------
+             ---4-----
+             ----5----
+             -----6---
+             ------7--
+             -------8-
+             -------92",
+        )
+        .unwrap();
+
+        assert!(board.diff(&board).is_empty());
+        assert_eq!(
+            board.diff(&other),
+            vec![
+                (
+                    Position { row: 8, column: 7 },
+                    Field::empty(),
+                    Field::from_u8(9)
+                ),
+                (
+                    Position { row: 8, column: 8 },
+                    Field::from_u8(9),
+                    Field::from_u8(2)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_symmetries_of_clues() {
+        // The "sudokus/starry.txt" board is only symmetric from left to right
+        let starry = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---3-5---
+             53--4--68
+             ---6-2---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        assert_eq!(starry.symmetry_of_clues(), vec![Symmetry::VerticalMirror]);
+
+        let diagonal = Board::try_from(
+            "1--------
+             -2-------
+             --3------
+             ---4-----
+             ----5----
+             -----6---
+             ------7--
+             -------8-
+             --------9",
+        )
+        .unwrap();
+
+        assert_eq!(
+            diagonal.symmetry_of_clues(),
+            vec![
+                Symmetry::Rotational,
+                Symmetry::Diagonal,
+                Symmetry::AntiDiagonal
+            ]
+        );
+
+        let no_symmetry = Board::try_from(format!("-1{}", "-".repeat(79))).unwrap();
+        assert!(no_symmetry.symmetry_of_clues().is_empty());
+    }
+
+    #[test]
+    fn iterates_empty_positions() {
+        // The board is "sudokus/starry.txt" solved except for three fields
+        let board = Board::try_from(
+            "-13529784
+             742836519
+             985174326
+             269385147
+             53194-268
+             874612935
+             426751893
+             397268451
+             15849367-",
+        )
+        .unwrap();
+
+        assert_eq!(
+            board.empty_positions().collect::<Vec<Position>>(),
+            vec![
+                Position { row: 0, column: 0 },
+                Position { row: 4, column: 5 },
+                Position { row: 8, column: 8 }
+            ]
+        );
+    }
+
+    #[test]
+    fn places_a_field_on_a_copy() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let position = Position { row: 0, column: 1 };
+
+        let placed = board.with_field(position, 1).unwrap();
+        assert_eq!(placed.get_field(position), &Field::from_u8(1));
+        assert!(!placed.is_given(position));
+        assert!(board.is_empty_at(position));
+
+        // A digit placed by solving can be replaced, but a given can't
+        let replaced = placed.with_field(position, 7).unwrap();
+        assert_eq!(replaced.get_field(position), &Field::from_u8(7));
+        assert_eq!(
+            board.with_field(Position { row: 0, column: 0 }, 9),
+            Err(FieldParseError::SudokuRuleViolation)
+        );
+
+        // 6 is already used in the row, and 0 is not a digit
+        assert_eq!(
+            board.with_field(position, 6),
+            Err(FieldParseError::SudokuRuleViolation)
+        );
+        assert_eq!(
+            board.with_field(position, 0),
+            Err(FieldParseError::InvalidCharacter)
+        );
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod solution_tests {
+    use super::*;
+
+    #[test]
+    fn finds_first_solution_on_board() {
+        // The board is "sudokus/starry.txt"
+        let board = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---3-5---
+             53--4--68
+             ---6-2---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        let solution_count = board.count_solutions(None, None);
+        assert_eq!(solution_count, 1);
+
+        let solved_board = board.first_solution().expect("Could not solve test board");
+
+        let solution = Board::try_from(
+            "613529784
+             742836519
+             985174326
+             269385147
+             531947268
+             874612935
+             426751893
+             397268451
+             158493672",
+        )
+        .unwrap();
+
+        assert_eq!(solved_board, solution);
+    }
+
+    #[test]
+    fn getting_first_solution_fails_on_unsolveable_board() {
+        // The board is "sudokus/starry.txt", but with an added 7 in the center
+        let board = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---3-5---
+             53--4--68
+             ---672---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        let solution_count = board.count_solutions(None, None);
+        assert_eq!(solution_count, 0);
+
+        let result = board.first_solution();
+
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap(), SudokuSolveError::Exhausted);
+    }
+
+    #[test]
+    fn solve_iter_fc_finds_the_same_solutions_in_fewer_steps() {
+        for input in [
+            include_str!("../sudokus/starry.txt"),
+            include_str!("../sudokus/turbine.txt"),
+            STARRY_MULTIPLE_SOLUTIONS,
+        ] {
+            let board = Board::try_from(input).unwrap();
+
+            let solutions = board
+                .solve_iter_fc()
+                .filter(|(_, is_solved)| *is_solved)
+                .map(|(board, _)| board)
+                .collect::<Vec<Board>>();
+
+            assert_eq!(solutions, board.solutions().collect::<Vec<Board>>());
+            assert!(board.solve_iter_fc().count() < board.solve_iter().count());
+        }
+    }
+
+    #[test]
+    fn solve_iter_sampled_emits_every_nth_board_and_the_solution() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let all_boards = board.solve_iter().collect::<Vec<(Board, bool)>>();
+        let sampled = board
+            .solve_iter_sampled(100)
+            .collect::<Vec<(Board, bool)>>();
+
+        // The solution is emitted as iteration 228, which is not a multiple of 100
+        assert_eq!(sampled.len(), all_boards.len() / 100 + 1);
+        assert_eq!(sampled[0], all_boards[99]);
+        assert_eq!(sampled[1], all_boards[199]);
+        assert_eq!(sampled[2], (board.first_solution().unwrap(), true));
+
+        assert_eq!(board.solve_iter_sampled(0).count(), all_boards.len());
+    }
+
+    #[test]
+    fn solve_iter_verbose_emits_the_changed_position() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let mut previous = board;
+        let mut placed_boards = Vec::new();
+
+        for (emitted, is_solved, changed) in board.solve_iter_verbose() {
+            let changed = changed.unwrap();
+            let diff = previous.diff(&emitted);
+
+            assert_eq!(diff.len(), 1);
+            assert_eq!(diff[0].0, changed);
+
+            if emitted.is_filled_at(changed) {
+                placed_boards.push((emitted, is_solved));
+            }
+
+            previous = emitted;
+        }
+
+        // The placements are exactly the boards emitted by solve_iter
+        assert_eq!(placed_boards, board.solve_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn first_solution_with_calls_back_on_every_step() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let mut steps = Vec::new();
+
+        let solution = board.first_solution_with(|step| steps.push(*step));
+
+        assert_eq!(solution, board.first_solution());
+        assert_eq!(steps.last(), solution.as_ref().ok());
+        assert_eq!(
+            steps,
+            board
+                .solve_iter()
+                .take(steps.len())
+                .map(|(board, _)| board)
+                .collect::<Vec<Board>>()
+        );
+    }
+
+    #[test]
+    fn first_solution_timeout_solves_fast_boards() {
+        // The "sudokus/alien.txt" board is solved before the clock is ever checked
+        let board = Board::try_from(include_str!("../sudokus/alien.txt")).unwrap();
+
+        assert_eq!(
+            board.first_solution_timeout(Duration::ZERO),
+            board.first_solution()
+        );
+        assert_eq!(
+            board.first_solution_timeout(Duration::from_secs(60)),
+            board.first_solution()
+        );
+    }
+
+    #[test]
+    fn first_solution_timeout_times_out() {
+        // The "sudokus/turbine.txt" board needs thousands of iterations to solve
+        let board = Board::try_from(include_str!("../sudokus/turbine.txt")).unwrap();
+
+        assert_eq!(
+            board.first_solution_timeout(Duration::ZERO),
+            Err(SudokuSolveError::TimedOut)
+        );
+        assert_eq!(
+            board.first_solution_timeout(Duration::from_secs(60)),
+            board.first_solution()
+        );
+    }
+
+    #[test]
+    fn solve_fast_finds_the_same_solution_as_backtracking() {
+        for board in [
+            include_str!("../sudokus/oneeighty.txt"),
+            include_str!("../sudokus/starry.txt"),
+            include_str!("../sudokus/turbine.txt"),
+            include_str!("../sudokus/alien.txt"),
+        ] {
+            let board = Board::try_from(board).unwrap();
+            assert_eq!(board.solve_fast(), board.first_solution());
+        }
+    }
+
+    #[test]
+    fn solve_fast_fails_on_unsolvable_board() {
+        // The board is "sudokus/starry.txt", but with an added 7 in the center
+        let board = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---3-5---
+             53--4--68
+             ---672---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        assert_eq!(board.solve_fast(), Err(SudokuSolveError::Exhausted));
+    }
+
+    #[test]
+    fn solution_path_replays_the_solve() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let path = board.solution_path().unwrap();
+
+        assert_eq!(path.len(), board.empty_positions().count());
+
+        let mut replayed = board;
+
+        for (position, field) in path {
+            assert!(replayed.valid_number_at_position(position, &field));
+            replayed.put_field(position, field);
+        }
+
+        assert_eq!(replayed, board.first_solution().unwrap());
+    }
+
+    #[test]
+    fn solve_iter_cancellable_ends_once_cancelled() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let mut iterator = board.solve_iter_cancellable(Arc::clone(&cancel));
+
+        assert!(iterator.by_ref().take(10).eq(board.solve_iter().take(10)));
+
+        cancel.store(true, atomic::Ordering::Relaxed);
+        assert_eq!(iterator.next(), None);
+
+        cancel.store(false, atomic::Ordering::Relaxed);
+        assert_eq!(iterator.next(), None);
+
+        // Without cancelling, the iterator runs like solve_iter
+        assert!(board
+            .solve_iter_cancellable(Arc::new(AtomicBool::new(false)))
+            .eq(board.solve_iter()));
+    }
+
+    #[test]
+    fn solve_iter_with_initial_starts_with_the_board() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let mut iterator = board.solve_iter_with_initial();
+
+        assert_eq!(iterator.next(), Some((board, false)));
+        assert!(iterator.eq(board.solve_iter()));
+    }
+
+    #[test]
+    fn unique_solution_requires_exactly_one_solution() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+
+        assert_eq!(board.unique_solution(), Ok(solution));
+        assert_eq!(solution.unique_solution(), Ok(solution));
+
+        assert_eq!(
+            Board::try_from(STARRY_MULTIPLE_SOLUTIONS)
+                .unwrap()
+                .unique_solution(),
+            Err(SudokuSolveError::MultipleSolutions)
+        );
+
+        // The board is "sudokus/starry.txt", but with an added 7 in the center
+        let unsolvable_board = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---3-5---
+             53--4--68
+             ---672---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        assert_eq!(
+            unsolvable_board.unique_solution(),
+            Err(SudokuSolveError::Exhausted)
+        );
+    }
+
+    #[test]
+    fn detects_a_field_without_candidates_before_solving() {
+        // The last field in the first row can not be filled, since its row holds 1-8
+        // and its column holds 9
+        let board = Board::try_from(
+            "12345678-
+             --------9
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------",
+        )
+        .unwrap();
+
+        assert_eq!(
+            board.find_contradiction(),
+            Some(Position { row: 0, column: 8 })
+        );
+        let contradiction = SudokuSolveError::Contradiction {
+            position: Position { row: 0, column: 8 },
+        };
+
+        assert_eq!(board.count_solutions(None, None), 0);
+        assert_eq!(board.first_solution().err().as_ref(), Some(&contradiction));
+        assert_eq!(board.unique_solution().err().as_ref(), Some(&contradiction));
+        assert_eq!(board.solve_fast().err().as_ref(), Some(&contradiction));
+        assert_eq!(
+            contradiction.to_string(),
+            "The sudoku is unsolvable, as no digit fits in row 1, column 9"
+        );
+    }
+
+    #[test]
+    fn count_solutions_returns_a_single_solution() {
+        // The board is "sudokus/turbine.txt"
+        let board = Board::try_from(
+            "-1-79----
+             -3-5---91
+             --91--5--
+             ------182
+             1---2---4
+             248------
+             --6--92--
+             32---4-7-
+             ----31-6-",
+        )
+        .unwrap();
+
+        assert_eq!(board.count_solutions(None, None), 1);
+    }
+
+    // The board is "sudokus/starry.txt", but the center 4 is removed
+    const STARRY_MULTIPLE_SOLUTIONS: &str = "6-------4
+                                             -42-3-51-
+                                             -85---32-
+                                             ---3-5---
+                                             53-----68
+                                             ---6-2---
+                                             -26-5-89-
+                                             -97---45-
+                                             1-------2";
+
+    #[test]
+    fn count_solutions_returns_multiple_solutions() {
+        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert_eq!(board.count_solutions(None, None), 21);
+    }
+
+    #[test]
+    fn count_solutions_respects_max_solutions() {
+        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert_eq!(board.count_solutions(Some(10), None), 10);
+    }
+
+    #[test]
+    fn count_solutions_respects_max_iterations() {
+        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert_eq!(board.count_solutions(None, Some(10_000)), 13);
+    }
+
+    #[test]
+    fn count_solutions_respects_both_max_iterations_and_max_solutions() {
+        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+
+        // 10_000 iterations will yield 13 solutions, however we set max 10
+        assert_eq!(board.count_solutions(Some(10), Some(10_000)), 10);
+
+        // 10_000 iterations will yield 13 solutions, however we set max 20
+        assert_eq!(board.count_solutions(Some(15), Some(10_000)), 13);
+    }
+
+    #[test]
+    fn count_solutions_agrees_with_filtering_solve_iter() {
+        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+
+        for max_iterations in [0, 1, 5_000, 10_000, 20_000] {
+            let filtered = board
+                .solve_iter()
+                .take(max_iterations)
+                .filter(|(_, is_solved)| *is_solved)
+                .count();
+
+            assert_eq!(board.count_solutions(None, Some(max_iterations)), filtered);
+        }
+
+        assert_eq!(board.count_solutions(Some(0), None), 0);
         assert_eq!(
-            board.get_field(Position { row: 1, column: 3 }),
-            &Field::from_u8(2)
+            board.count_and_collect(usize::MAX, None).1,
+            board.solutions().collect::<Vec<Board>>()
         );
     }
 
     #[test]
-    fn returns_next_empty_field() {
-        // The board is "sudokus/starry.txt" solved except for one field
-        let mut board = Board::try_from(
-            "613529784
-             742836519
-             985174326
-             269385147
-             53194-268
-             874612935
-             426751893
-             397268451
-             158493672",
+    fn count_and_collect_returns_the_first_solutions() {
+        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+
+        let (count, solutions) = board.count_and_collect(3, None);
+        assert_eq!(count, 21);
+        assert_eq!(solutions, board.solutions().take(3).collect::<Vec<_>>());
+
+        let (count, solutions) = board.count_and_collect(50, Some(10_000));
+        assert_eq!(count, 13);
+        assert_eq!(solutions.len(), 13);
+
+        assert_eq!(board.count_and_collect(0, None), (21, vec![]));
+    }
+
+    #[test]
+    fn detects_minimal_boards() {
+        // The board is "sudokus/oneeighty.txt", which has redundant fields
+        let board = Board::try_from(
+            "-349---28
+             2-------6
+             ---271---
+             -----2-6-
+             45-----39
+             -6-4-----
+             ---614---
+             3-------1
+             98---364-",
         )
         .unwrap();
 
+        // The board is "sudokus/oneeighty.txt" with redundant fields removed
+        let minimal_board = Board::try_from(
+            "-349---28
+             2--------
+             ----71---
+             -----2-6-
+             45-----39
+             -6-4-----
+             ---614---
+             3-------1
+             98---364-",
+        )
+        .unwrap();
+
+        assert!(board.has_unique_solution());
+        assert!(!board.is_minimal());
+
+        assert!(minimal_board.has_unique_solution());
+        assert!(minimal_board.is_minimal());
+
+        assert!(!Board::try_from(STARRY_MULTIPLE_SOLUTIONS)
+            .unwrap()
+            .is_minimal());
+    }
+
+    #[test]
+    fn finds_invalid_placements() {
+        let mut board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        assert_eq!(board.invalid_placements(), Ok(vec![]));
+
+        // The solution has 1 at the first empty field and 3 at the second. The 7 fits
+        // by the sudoku rules, but is wrong.
+        board.put_field(Position { row: 0, column: 1 }, Field::from_u8(7));
+        board.put_field(Position { row: 0, column: 2 }, Field::from_u8(3));
+
         assert_eq!(
-            board.next_empty_field(Position { row: 0, column: 4 }),
-            Some(Position { row: 4, column: 5 })
+            board.invalid_placements(),
+            Ok(vec![Position { row: 0, column: 1 }])
         );
 
-        board.put_field(Position { row: 4, column: 5 }, Field::from_u8(7));
+        assert_eq!(
+            Board::try_from(STARRY_MULTIPLE_SOLUTIONS)
+                .unwrap()
+                .invalid_placements(),
+            Err(SudokuSolveError::MultipleSolutions)
+        );
+    }
 
-        assert!(board
-            .next_empty_field(Position { row: 0, column: 4 })
-            .is_none());
+    #[test]
+    fn minimizes_a_board() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let minimized = board.minimize(42);
+
+        assert!(minimized.is_minimal());
+        assert!(minimized.givens().len() < board.givens().len());
+        assert!(minimized.givens().is_subset(&board.givens()));
+        assert_eq!(minimized.first_solution(), board.first_solution());
+        assert_eq!(board.minimize(42), minimized);
+
+        // A board with multiple solutions can't be minimized
+        let multiple_solutions = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert_eq!(multiple_solutions.minimize(42), multiple_solutions);
     }
-}
 
-#[allow(clippy::unwrap_used, clippy::expect_used)]
-#[cfg(test)]
-mod solution_tests {
-    use super::*;
+    #[test]
+    fn enumerates_distinct_minimal_boards() {
+        let solution = Board::try_from(include_str!("../sudokus/oneeighty.txt"))
+            .unwrap()
+            .first_solution()
+            .unwrap();
+        let minimal_boards = solution.enumerate_minimal(42, 2);
+
+        assert_eq!(minimal_boards.len(), 2);
+        assert_ne!(minimal_boards[0].canonical(), minimal_boards[1].canonical());
+
+        for minimal_board in &minimal_boards {
+            assert!(minimal_board.is_minimal());
+            assert_eq!(minimal_board.unique_solution(), Ok(solution));
+        }
+
+        let multiple_solutions = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert!(multiple_solutions.enumerate_minimal(42, 2).is_empty());
+    }
 
     #[test]
-    fn finds_first_solution_on_board() {
-        // The board is "sudokus/starry.txt"
-        let board = Board::try_from(
-            "6-------4
-             -42-3-51-
-             -85---32-
-             ---3-5---
-             53--4--68
-             ---6-2---
-             -26-5-89-
-             -97---45-
-             1-------2",
+    fn removes_a_random_clue() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let mut reduced_board = board;
+        let position = reduced_board.remove_random_clue(42).unwrap();
+
+        assert!(board.is_filled_at(position));
+        assert!(reduced_board.is_empty_at(position));
+        assert!(!reduced_board.givens().contains(&position));
+        assert_eq!(reduced_board.givens().len(), board.givens().len() - 1);
+        assert_eq!(reduced_board.unique_solution(), board.unique_solution());
+
+        let mut same_seed = board;
+        assert_eq!(same_seed.remove_random_clue(42), Some(position));
+
+        // Nothing can be removed from minimal boards or boards with multiple solutions
+        let mut minimized = board.minimize(42);
+        assert_eq!(minimized.remove_random_clue(42), None);
+        assert_eq!(minimized, board.minimize(42));
+
+        let mut multiple_solutions = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert_eq!(multiple_solutions.remove_random_clue(42), None);
+
+        // Only givens are removed, never the fields filled in by solving
+        for seed in 0..10 {
+            let mut solution = board.first_solution().unwrap();
+            let position = solution.remove_random_clue(seed).unwrap();
+
+            assert!(board.is_given(position));
+        }
+    }
+
+    #[test]
+    fn reveals_a_field_from_the_solution() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+        let (position, field) = board.reveal(42).unwrap();
+
+        assert!(board.is_empty_at(position));
+        assert_eq!(&field, solution.get_field(position));
+        assert_eq!(board.reveal(42), Some((position, field)));
+
+        let mut easier_board = board;
+        assert_eq!(easier_board.add_clue(42), Some(position));
+
+        assert_eq!(solution.reveal(42), None);
+        assert_eq!(
+            Board::try_from(STARRY_MULTIPLE_SOLUTIONS)
+                .unwrap()
+                .reveal(42),
+            None
+        );
+    }
+
+    #[test]
+    fn adds_a_clue_from_the_solution() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+        let mut easier_board = board;
+        let position = easier_board.add_clue(42).unwrap();
+
+        assert!(board.is_empty_at(position));
+        assert_eq!(
+            easier_board.get_field(position),
+            solution.get_field(position)
+        );
+        assert!(easier_board.givens().contains(&position));
+        assert_eq!(easier_board.givens().len(), board.givens().len() + 1);
+
+        let mut same_seed = board;
+        assert_eq!(same_seed.add_clue(42), Some(position));
+
+        // Nothing can be added to full boards or boards with multiple solutions
+        let mut full_board = solution;
+        assert_eq!(full_board.add_clue(42), None);
+
+        let mut multiple_solutions = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+        assert_eq!(multiple_solutions.add_clue(42), None);
+        assert_eq!(
+            multiple_solutions,
+            Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap()
+        );
+    }
+
+    #[test]
+    fn solve_iter_with_order_changes_the_search() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+
+        let step_count = |iterator: BacktrackingIter| {
+            iterator
+                .enumerate()
+                .find(|(_, (_, is_solved))| *is_solved)
+                .map(|(index, (board, _))| (index, board))
+                .unwrap()
+        };
+
+        let (ascending_steps, ascending_solution) =
+            step_count(board.solve_iter_with_order([1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        let (descending_steps, descending_solution) =
+            step_count(board.solve_iter_with_order([9, 8, 7, 6, 5, 4, 3, 2, 1]));
+
+        assert_eq!(ascending_steps, 228);
+        assert_ne!(descending_steps, ascending_steps);
+        assert_eq!(descending_solution, ascending_solution);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation of 1-9")]
+    fn solve_iter_with_order_panics_on_an_invalid_order() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        board.solve_iter_with_order([1, 1, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn solve_iter_anti_knight_keeps_digits_a_knight_move_apart() {
+        let (solution, _) = Board::empty()
+            .solve_iter_anti_knight()
+            .find(|(_, is_solved)| *is_solved)
+            .unwrap();
+
+        assert!(solution.is_complete_and_valid());
+        assert!(PositionIter::from_first_field().all(|position| {
+            position.knight_moves().all(|knight_position| {
+                solution.get_field(knight_position) != solution.get_field(position)
+            })
+        }));
+
+        // The two 1s are a knight's move apart
+        let breaking_board = Board::try_from(
+            "--1------
+             ----1----
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------",
         )
         .unwrap();
 
-        let solution_count = board.count_solutions(None, None);
-        assert_eq!(solution_count, 1);
+        assert_eq!(breaking_board.solve_iter_anti_knight().next(), None);
+        assert!(breaking_board.solve_iter().next().is_some());
+    }
 
-        let solved_board = board.first_solution().expect("Could not solve test board");
+    #[test]
+    fn solve_iter_anti_king_keeps_digits_apart_diagonally() {
+        let (solution, _) = Board::empty()
+            .solve_iter_anti_king()
+            .find(|(_, is_solved)| *is_solved)
+            .unwrap();
+
+        assert!(solution.is_complete_and_valid());
+        assert!(PositionIter::from_first_field().all(|position| {
+            position
+                .diagonal_neighbours()
+                .all(|neighbour| solution.get_field(neighbour) != solution.get_field(position))
+        }));
+
+        // The two 1s are diagonally adjacent across the border between two boxes
+        let breaking_board = Board::try_from(
+            "--1------
+             ---1-----
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------",
+        )
+        .unwrap();
 
-        let solution = Board::try_from(
-            "613529784
+        assert_eq!(breaking_board.solve_iter_anti_king().next(), None);
+        assert!(breaking_board.solve_iter().next().is_some());
+    }
+
+    #[test]
+    fn solve_iter_locked_keeps_locked_guesses() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let position = Position { row: 0, column: 1 };
+        let locked = [position].into_iter().collect();
+
+        // A correct guess that is not a given stays in the solution
+        let mut board_with_guess = board;
+        board_with_guess.put_field(position, Field::from_u8(1));
+
+        let (solved_board, _) = board_with_guess
+            .solve_iter_locked(&locked)
+            .find(|(_, is_solved)| *is_solved)
+            .unwrap();
+
+        assert!(!solved_board.is_given(position));
+        assert_eq!(solved_board, board.first_solution().unwrap());
+
+        // A locked empty field can never be filled, so there is nothing to search, even
+        // though the board itself has a solution
+        assert!(board.solve_iter().any(|(_, is_solved)| is_solved));
+        assert_eq!(board.solve_iter_locked(&locked).next(), None);
+    }
+
+    #[test]
+    fn solve_iter_from_leaves_earlier_fields_untouched() {
+        // The board is "sudokus/starry.txt" solved except for the first and last row
+        let board = Board::try_from(
+            "6-------4
              742836519
              985174326
              269385147
@@ -391,42 +3036,106 @@ mod solution_tests {
              874612935
              426751893
              397268451
-             158493672",
+             1-------2",
         )
         .unwrap();
 
-        assert_eq!(solved_board, solution);
+        let (solved_board, _) = board
+            .solve_iter_from(Position { row: 8, column: 0 })
+            .find(|(_, is_solved)| *is_solved)
+            .unwrap();
+
+        assert_eq!(
+            solved_board,
+            Board::try_from(
+                "6-------4
+                 742836519
+                 985174326
+                 269385147
+                 531947268
+                 874612935
+                 426751893
+                 397268451
+                 158493672",
+            )
+            .unwrap()
+        );
     }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod candidate_tests {
+    use super::*;
+
+    // The "sudokus/starry.txt" board
+    const TEST_SUDOKU: &str = "6-------4
+                               -42-3-51-
+                               -85---32-
+                               ---3-5---
+                               53--4--68
+                               ---6-2---
+                               -26-5-89-
+                               -97---45-
+                               1-------2";
 
     #[test]
-    fn getting_first_solution_fails_on_unsolveable_board() {
-        // The board is "sudokus/starry.txt", but with an added 7 in the center
-        let board = Board::try_from(
-            "6-------4
-             -42-3-51-
-             -85---32-
-             ---3-5---
-             53--4--68
-             ---672---
-             -26-5-89-
-             -97---45-
-             1-------2",
-        )
-        .unwrap();
+    fn gets_candidates_for_a_position() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
 
-        let solution_count = board.count_solutions(None, None);
-        assert_eq!(solution_count, 0);
+        assert_eq!(
+            board.candidates(Position { row: 0, column: 1 }),
+            vec![Field::from_u8(1), Field::from_u8(7)]
+        );
 
-        let result = board.first_solution();
+        assert!(board.candidates(Position { row: 0, column: 0 }).is_empty());
+    }
 
-        assert!(result.is_err());
-        assert_eq!(result.err().unwrap(), SudokuSolveError::Unsolvable);
+    #[test]
+    fn finds_single_candidates() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+
+        assert_eq!(
+            board.find_single_candidates(),
+            vec![(Position { row: 8, column: 1 }, Field::from_u8(5))]
+        );
     }
 
     #[test]
-    fn count_solutions_returns_a_single_solution() {
-        // The board is "sudokus/turbine.txt"
-        let board = Board::try_from(
+    fn fills_from_another_board() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+
+        let mut filled = board;
+        assert_eq!(filled.fill_from(&solution), 50);
+        assert_eq!(filled, solution);
+        assert_eq!(filled.givens(), board.givens());
+        assert_eq!(filled.fill_from(&solution), 0);
+
+        // Only fields that are legal on the board are copied. The first row of the
+        // other board has a 1 that fits, and a 6 that is already used in the row.
+        let mut other = Board::empty();
+        other.put_field(Position { row: 0, column: 1 }, Field::from_u8(1));
+        other.put_field(Position { row: 0, column: 2 }, Field::from_u8(6));
+        other.put_field(Position { row: 0, column: 0 }, Field::from_u8(9));
+
+        let mut partially_filled = board;
+        assert_eq!(partially_filled.fill_from(&other), 1);
+        assert_eq!(
+            partially_filled.get_field(Position { row: 0, column: 1 }),
+            &Field::from_u8(1)
+        );
+        assert!(partially_filled.is_empty_at(Position { row: 0, column: 2 }));
+        assert_eq!(
+            partially_filled.get_field(Position { row: 0, column: 0 }),
+            &Field::from_u8(6)
+        );
+    }
+
+    #[test]
+    fn fill_obvious_solves_an_easy_board() {
+        // The "sudokus/turbine.txt" board can be solved with singles only
+        let mut board = Board::try_from(
             "-1-79----
              -3-5---91
              --91--5--
@@ -439,47 +3148,129 @@ mod solution_tests {
         )
         .unwrap();
 
-        assert_eq!(board.count_solutions(None, None), 1);
+        let solution = board.first_solution().unwrap();
+        let mut rounds = 0;
+
+        while board.fill_obvious() {
+            rounds += 1;
+        }
+
+        assert!(rounds > 1);
+        assert_eq!(board, solution);
+        assert!(!board.fill_obvious());
     }
 
-    // The board is "sudokus/starry.txt", but the center 4 is removed
-    const STARRY_MULTIPLE_SOLUTIONS: &str = "6-------4
-                                             -42-3-51-
-                                             -85---32-
-                                             ---3-5---
-                                             53-----68
-                                             ---6-2---
-                                             -26-5-89-
-                                             -97---45-
-                                             1-------2";
+    #[test]
+    fn rates_difficulty_by_the_techniques_needed() {
+        let easy_board = Board::try_from(include_str!("../sudokus/alien.txt")).unwrap();
+        let medium_board = Board::try_from(TEST_SUDOKU).unwrap();
+
+        assert_eq!(easy_board.difficulty(), Difficulty::Easy);
+        assert_eq!(medium_board.difficulty(), Difficulty::Medium);
+        assert_eq!(
+            medium_board.first_solution().unwrap().difficulty(),
+            Difficulty::Easy
+        );
+        assert_eq!(Board::empty().difficulty(), Difficulty::Expert);
+    }
+
+    #[test]
+    fn checks_if_a_board_is_logically_solvable() {
+        assert!(Board::try_from(TEST_SUDOKU)
+            .unwrap()
+            .is_logically_solvable());
+        assert!(!Board::empty().is_logically_solvable());
+    }
+
+    #[test]
+    fn estimates_difficulty() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let solution = board.first_solution().unwrap();
+
+        // The "sudokus/turbine.txt" board is harder than "sudokus/starry.txt"
+        let harder_board = Board::try_from(
+            "-1-79----
+             -3-5---91
+             --91--5--
+             ------182
+             1---2---4
+             248------
+             --6--92--
+             32---4-7-
+             ----31-6-",
+        )
+        .unwrap();
 
-    #[test]
-    fn count_solutions_returns_multiple_solutions() {
-        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
-        assert_eq!(board.count_solutions(None, None), 21);
+        assert_eq!(solution.difficulty_estimate(), 0);
+        assert!(board.difficulty_estimate() > 0);
+        assert!(harder_board.difficulty_estimate() > board.difficulty_estimate());
     }
 
     #[test]
-    fn count_solutions_respects_max_solutions() {
-        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
-        assert_eq!(board.count_solutions(Some(10), None), 10);
+    fn finds_hidden_singles() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let hidden_singles = board.find_hidden_singles();
+
+        let expected = [
+            (0, 2, 3),
+            (0, 3, 5),
+            (3, 1, 6),
+            (4, 6, 2),
+            (5, 8, 5),
+            (8, 1, 5),
+            (3, 0, 2),
+            (8, 6, 6),
+            (0, 7, 8),
+        ]
+        .map(|(row, column, value)| (Position { row, column }, Field::from_u8(value)));
+
+        assert_eq!(hidden_singles, expected);
     }
 
     #[test]
-    fn count_solutions_respects_max_iterations() {
-        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
-        assert_eq!(board.count_solutions(None, Some(10_000)), 13);
+    fn hints_the_easiest_move() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let hint = board.hint().unwrap();
+
+        assert_eq!(hint.technique(), Technique::NakedSingle);
+        assert_eq!(hint.position(), Position { row: 8, column: 1 });
+        assert_eq!(hint.field(), Field::from_u8(5));
+        assert_eq!(
+            hint.reason(),
+            "5 is the only digit that fits in row 9, column 2"
+        );
     }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod generator_tests {
+    use super::*;
 
     #[test]
-    fn count_solutions_respects_both_max_iterations_and_max_solutions() {
-        let board = Board::try_from(STARRY_MULTIPLE_SOLUTIONS).unwrap();
+    fn generates_a_unique_sudoku_with_the_target_clues() {
+        let board = Board::generate(42, 30).unwrap();
 
-        // 10_000 iterations will yield 13 solutions, however we set max 10
-        assert_eq!(board.count_solutions(Some(10), Some(10_000)), 10);
+        assert_eq!(board.clues().len(), 30);
+        assert_eq!(board.givens().len(), 30);
+        assert!(board.has_unique_solution());
+        assert_eq!(Board::generate(42, 30), Ok(board));
+        assert_ne!(Board::generate(43, 30), Ok(board));
 
-        // 10_000 iterations will yield 13 solutions, however we set max 20
-        assert_eq!(board.count_solutions(Some(15), Some(10_000)), 13);
+        assert_eq!(
+            Board::generate(42, Board::MIN_CLUES - 1),
+            Err(SudokuGenerateError::TooFewClues)
+        );
+    }
+
+    #[test]
+    fn generates_a_unique_sudoku_with_the_target_difficulty() {
+        let board = Board::generate_difficulty(1, Difficulty::Easy);
+
+        assert_eq!(board.difficulty(), Difficulty::Easy);
+        assert!(board.has_unique_solution());
+        assert_eq!(board.givens().len(), 81 - board.empty_positions().count());
+        assert_eq!(board, Board::generate_difficulty(1, Difficulty::Easy));
     }
 }
 
@@ -499,6 +3290,107 @@ mod validation_tests {
                                -97---45-
                                1-------2";
 
+    #[test]
+    fn validates_a_board() {
+        let mut board = Board::try_from(TEST_SUDOKU).unwrap();
+        assert_eq!(board.validate(), Ok(()));
+        assert_eq!(board.first_solution().unwrap().validate(), Ok(()));
+
+        // Sneak in a second 6 in the first row
+        board.put_field(Position { row: 0, column: 4 }, Field::from_u8(6));
+
+        let expected_violations = [
+            Position { row: 0, column: 0 },
+            Position { row: 0, column: 4 },
+        ]
+        .into_iter()
+        .map(|position| (position, FieldParseError::SudokuRuleViolation))
+        .collect::<HashSet<(Position, FieldParseError)>>();
+
+        assert_eq!(
+            board.validate(),
+            Err(SudokuParseError::ParseErrors(expected_violations))
+        );
+    }
+
+    #[test]
+    fn checks_if_a_board_is_complete_and_valid() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let mut solution = board.first_solution().unwrap();
+
+        assert!(!board.is_complete_and_valid());
+        assert!(solution.is_complete_and_valid());
+
+        // Swap the first two digits of the first row, breaking the column and box rules
+        let first = *solution.get_field(Position { row: 0, column: 0 });
+        let second = *solution.get_field(Position { row: 0, column: 1 });
+        solution.put_field(Position { row: 0, column: 0 }, second);
+        solution.put_field(Position { row: 0, column: 1 }, first);
+
+        assert!(!solution.is_complete_and_valid());
+    }
+
+    #[test]
+    fn checks_if_a_board_is_a_solution_of_a_puzzle() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let solution = board.first_solution().unwrap();
+
+        assert!(board.is_solution(&solution));
+        assert!(!board.is_solution(&board));
+
+        // Relabeling the digits keeps the solution complete and valid, but not a
+        // solution of the puzzle
+        let relabeled =
+            Board::try_from(solution.to_grid().map(|row| row.map(|value| value % 9 + 1))).unwrap();
+
+        assert!(relabeled.is_complete_and_valid());
+        assert!(!board.is_solution(&relabeled));
+    }
+
+    #[test]
+    fn checks_if_a_grid_is_valid() {
+        let grid = Board::try_from(TEST_SUDOKU).unwrap().to_grid();
+        assert!(Board::is_valid_grid(&grid));
+        assert!(Board::is_valid_grid(&[[0; 9]; 9]));
+
+        let mut invalid_value = grid;
+        invalid_value[0][1] = 10;
+        assert!(!Board::is_valid_grid(&invalid_value));
+
+        // The first row, column and box all have a 6 in the top left corner
+        for position in [(0, 4), (4, 0), (1, 1)] {
+            let mut duplicate = grid;
+            duplicate[position.0][position.1] = 6;
+            assert!(!Board::is_valid_grid(&duplicate));
+        }
+    }
+
+    #[test]
+    fn gets_conflict_details() {
+        let mut board = Board::try_from(TEST_SUDOKU).unwrap();
+        assert_eq!(board.conflict_details(), vec![]);
+
+        // Sneak in a second 6 in the first column and box
+        board.put_field(Position { row: 1, column: 0 }, Field::from_u8(6));
+
+        let conflicts = board.conflict_details();
+        let expected_conflicts = [
+            (Position { row: 0, column: 0 }, ConflictKind::Column),
+            (Position { row: 0, column: 0 }, ConflictKind::Box),
+            (Position { row: 1, column: 0 }, ConflictKind::Column),
+            (Position { row: 1, column: 0 }, ConflictKind::Box),
+        ]
+        .map(|(position, kind)| Conflict {
+            position,
+            field: Field::from_u8(6),
+            kind,
+        });
+
+        assert_eq!(conflicts, expected_conflicts);
+        assert_eq!(conflicts[0].to_string(), "6 appears twice in column 1");
+        assert_eq!(conflicts[1].to_string(), "6 appears twice in box 1");
+    }
+
     #[test]
     fn number_used_in_row() {
         let board = Board::try_from(TEST_SUDOKU).unwrap();
@@ -542,6 +3434,214 @@ mod validation_tests {
     }
 }
 
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+
+    // The "sudokus/starry.txt" board
+    const TEST_SUDOKU: &str = "6-------4
+                               -42-3-51-
+                               -85---32-
+                               ---3-5---
+                               53--4--68
+                               ---6-2---
+                               -26-5-89-
+                               -97---45-
+                               1-------2";
+
+    #[test]
+    fn swaps_bands() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let swapped = board.swap_bands(0, 2).unwrap();
+
+        let expected = Board::try_from(
+            "-26-5-89-
+             -97---45-
+             1-------2
+             ---3-5---
+             53--4--68
+             ---6-2---
+             6-------4
+             -42-3-51-
+             -85---32-",
+        )
+        .unwrap();
+
+        assert_eq!(swapped, expected);
+        assert_eq!(swapped.givens(), expected.givens());
+        assert_eq!(swapped.swap_bands(2, 0).unwrap(), board);
+        assert_eq!(board.swap_bands(1, 1).unwrap(), board);
+        assert_eq!(
+            board.swap_bands(0, 3),
+            Err(SudokuTransformError::InvalidIndex)
+        );
+    }
+
+    #[test]
+    fn swaps_stacks() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let swapped = board.swap_stacks(0, 1).unwrap();
+
+        let expected = Board::try_from(
+            "---6----4
+             -3--4251-
+             ----8532-
+             3-5------
+             -4-53--68
+             6-2------
+             -5--2689-
+             ----9745-
+             ---1----2",
+        )
+        .unwrap();
+
+        assert_eq!(swapped, expected);
+        assert_eq!(swapped.givens(), expected.givens());
+        assert_eq!(swapped.swap_stacks(1, 0).unwrap(), board);
+        assert_eq!(
+            board.swap_stacks(5, 1),
+            Err(SudokuTransformError::InvalidIndex)
+        );
+    }
+
+    #[test]
+    fn equivalent_boards_have_the_same_canonical_form() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let canonical = board.canonical();
+
+        // Relabel every digit d as 10 - d, and transpose the board
+        let grid = board.to_grid();
+        let mut relabeled_and_transposed = [[0; 9]; 9];
+
+        for (row, values) in grid.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                if *value != 0 {
+                    relabeled_and_transposed[column][row] = 10 - value;
+                }
+            }
+        }
+
+        let equivalent = Board::try_from(relabeled_and_transposed)
+            .unwrap()
+            .swap_bands(0, 1)
+            .unwrap()
+            .swap_columns(3, 5)
+            .unwrap();
+
+        assert_ne!(equivalent, board);
+        assert_eq!(equivalent.canonical(), canonical);
+        assert_eq!(canonical.canonical(), canonical);
+        assert_eq!(canonical.count_solutions(Some(2), None), 1);
+        assert_eq!(canonical.givens().len(), 31);
+
+        let other_board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        assert_ne!(other_board.canonical(), canonical);
+    }
+
+    #[test]
+    fn shuffles_into_an_equivalent_board() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let shuffled = board.shuffle_equivalent(42);
+
+        assert_ne!(shuffled, board);
+        assert_eq!(shuffled, board.shuffle_equivalent(42));
+        assert_ne!(shuffled, board.shuffle_equivalent(43));
+        assert_eq!(shuffled.givens().len(), board.givens().len());
+        assert!(shuffled.has_unique_solution());
+        assert_eq!(shuffled.canonical(), board.canonical());
+    }
+
+    #[test]
+    fn complements_the_digits() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let complement = board.complement();
+
+        let expected = Board::try_from(
+            "4-------6
+             -68-7-59-
+             -25---78-
+             ---7-5---
+             57--6--42
+             ---4-8---
+             -84-5-21-
+             -13---65-
+             9-------8",
+        )
+        .unwrap();
+
+        assert_eq!(complement, expected);
+        assert_eq!(complement.givens(), board.givens());
+        assert_eq!(complement.complement(), board);
+        assert_eq!(
+            complement.first_solution().unwrap(),
+            board.first_solution().unwrap().complement()
+        );
+    }
+
+    #[test]
+    fn swaps_rows_within_a_band() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let swapped = board.swap_rows(3, 5).unwrap();
+
+        let expected = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---6-2---
+             53--4--68
+             ---3-5---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        assert_eq!(swapped, expected);
+        assert_eq!(swapped.givens(), expected.givens());
+        assert_eq!(swapped.swap_rows(5, 3).unwrap(), board);
+        assert_eq!(
+            board.swap_rows(2, 3),
+            Err(SudokuTransformError::NotInSameBand)
+        );
+        assert_eq!(
+            board.swap_rows(8, 9),
+            Err(SudokuTransformError::InvalidIndex)
+        );
+    }
+
+    #[test]
+    fn swaps_columns_within_a_stack() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let swapped = board.swap_columns(6, 8).unwrap();
+
+        let expected = Board::try_from(
+            "6-----4--
+             -42-3--15
+             -85----23
+             ---3-5---
+             53--4-86-
+             ---6-2---
+             -26-5--98
+             -97----54
+             1-----2--",
+        )
+        .unwrap();
+
+        assert_eq!(swapped, expected);
+        assert_eq!(swapped.givens(), expected.givens());
+        assert_eq!(swapped.swap_columns(8, 6).unwrap(), board);
+        assert_eq!(
+            board.swap_columns(0, 8),
+            Err(SudokuTransformError::NotInSameStack)
+        );
+        assert_eq!(
+            board.swap_columns(10, 0),
+            Err(SudokuTransformError::InvalidIndex)
+        );
+    }
+}
+
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 #[cfg(test)]
 mod to_and_from_string_test {
@@ -567,6 +3667,21 @@ mod to_and_from_string_test {
         assert_eq!(board.err().unwrap(), SudokuParseError::InvalidLength);
     }
 
+    #[test]
+    fn reads_dots_and_zeros_as_empty_fields() {
+        let with_dots = TEST_SUDOKU.replace('-', ".");
+        let with_zeros = TEST_SUDOKU.replace('-', "0");
+
+        assert_eq!(
+            Board::try_from(with_dots.as_str()).unwrap(),
+            Board::try_from(TEST_SUDOKU).unwrap()
+        );
+        assert_eq!(
+            Board::try_from(with_zeros.as_str()).unwrap(),
+            Board::try_from(TEST_SUDOKU).unwrap()
+        );
+    }
+
     #[test]
     fn returns_rule_violations_and_invalid_chars() {
         // The "sudokus/oneeighty.txt" board modified
@@ -721,6 +3836,222 @@ mod to_and_from_string_test {
         assert_eq!(board.to_string(), expected_board);
     }
 
+    #[test]
+    fn from_byte_array_matches_string_parsing() {
+        let bytes: &[u8; 81] =
+            b"-349...282.......6...271........2.6.45.....39.6.4........614...3-------198   364 ";
+
+        let board = Board::try_from(bytes).unwrap();
+
+        assert_eq!(board, Board::try_from(TEST_SUDOKU).unwrap());
+    }
+
+    #[test]
+    fn from_byte_array_reports_invalid_characters() {
+        let mut bytes = [b'-'; 81];
+        bytes[80] = b'x';
+
+        let expected_errors = {
+            let mut errors = HashSet::new();
+            errors.insert((
+                Position { row: 8, column: 8 },
+                FieldParseError::InvalidCharacter,
+            ));
+            errors
+        };
+
+        assert_eq!(
+            Board::try_from(bytes).err().unwrap(),
+            SudokuParseError::ParseErrors(expected_errors)
+        );
+    }
+
+    #[test]
+    fn from_grid_matches_string_parsing() {
+        let mut grid = [[0; 9]; 9];
+        grid[0] = [0, 3, 4, 9, 0, 0, 0, 2, 8];
+        grid[1] = [2, 0, 0, 0, 0, 0, 0, 0, 6];
+        grid[2] = [0, 0, 0, 2, 7, 1, 0, 0, 0];
+        grid[3] = [0, 0, 0, 0, 0, 2, 0, 6, 0];
+        grid[4] = [4, 5, 0, 0, 0, 0, 0, 3, 9];
+        grid[5] = [0, 6, 0, 4, 0, 0, 0, 0, 0];
+        grid[6] = [0, 0, 0, 6, 1, 4, 0, 0, 0];
+        grid[7] = [3, 0, 0, 0, 0, 0, 0, 0, 1];
+        grid[8] = [9, 8, 0, 0, 0, 3, 6, 4, 0];
+
+        assert_eq!(
+            Board::try_from(grid).unwrap(),
+            Board::try_from(TEST_SUDOKU).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_grid_reports_invalid_values_and_rule_violations() {
+        let mut grid = [[0; 9]; 9];
+        grid[0][0] = 10;
+        grid[4][4] = 5;
+        grid[4][8] = 5;
+
+        let expected_errors = [
+            (
+                Position { row: 0, column: 0 },
+                FieldParseError::InvalidCharacter,
+            ),
+            (
+                Position { row: 4, column: 4 },
+                FieldParseError::SudokuRuleViolation,
+            ),
+            (
+                Position { row: 4, column: 8 },
+                FieldParseError::SudokuRuleViolation,
+            ),
+        ]
+        .into_iter()
+        .collect::<HashSet<_>>();
+
+        assert_eq!(
+            Board::try_from(grid).err().unwrap(),
+            SudokuParseError::ParseErrors(expected_errors)
+        );
+    }
+
+    #[test]
+    fn from_clues_places_only_the_clues() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let clues = board
+            .givens()
+            .into_iter()
+            .map(|position| (position, board.get_field(position).value().unwrap()));
+
+        assert_eq!(Board::from_clues(clues).unwrap(), board);
+        assert_eq!(Board::from_clues([]).unwrap(), Board::empty());
+    }
+
+    #[test]
+    fn from_clues_reports_clashing_clues() {
+        let clues = [
+            (Position { row: 0, column: 0 }, 10),
+            (Position { row: 4, column: 4 }, 5),
+            (Position { row: 4, column: 8 }, 5),
+            (Position { row: 8, column: 8 }, 1),
+            (Position { row: 8, column: 8 }, 2),
+        ];
+
+        let expected_errors = [
+            (
+                Position { row: 0, column: 0 },
+                FieldParseError::InvalidCharacter,
+            ),
+            (
+                Position { row: 4, column: 4 },
+                FieldParseError::SudokuRuleViolation,
+            ),
+            (
+                Position { row: 4, column: 8 },
+                FieldParseError::SudokuRuleViolation,
+            ),
+            (
+                Position { row: 8, column: 8 },
+                FieldParseError::SudokuRuleViolation,
+            ),
+        ]
+        .into_iter()
+        .collect::<HashSet<_>>();
+
+        assert_eq!(
+            Board::from_clues(clues).err(),
+            Some(SudokuParseError::ParseErrors(expected_errors))
+        );
+    }
+
+    #[test]
+    fn to_grid_round_trips() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let grid = board.to_grid();
+
+        assert_eq!(grid[0], [0, 3, 4, 9, 0, 0, 0, 2, 8]);
+        assert_eq!(grid[8], [9, 8, 0, 0, 0, 3, 6, 4, 0]);
+        assert_eq!(Board::try_from(grid).unwrap(), board);
+    }
+
+    #[test]
+    fn displays_with_a_chosen_empty_character() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+
+        assert_eq!(board.display_with(' ', true), board.to_string());
+        assert_eq!(
+            board.display_with('.', true).lines().nth(1),
+            Some("|.34|9..|.28|")
+        );
+
+        let expected = ".349...28
+2.......6
+...271...
+.....2.6.
+45.....39
+.6.4.....
+...614...
+3.......1
+98...364.
+";
+
+        assert_eq!(board.display_with('.', false), expected);
+        assert!(board.matches_line(&board.display_with('0', false)));
+    }
+
+    #[test]
+    fn matches_line_compares_against_a_parsed_string() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let line =
+            "-349---282-------6---271--------2-6-45-----39-6-4--------614---3-------198---364-";
+
+        assert!(board.matches_line(line));
+        assert!(board.matches_line(&line.replace('-', ".")));
+        assert!(board.matches_line(TEST_SUDOKU));
+        assert!(!board.matches_line(&line.replacen('-', "1", 1)));
+        assert!(!board.matches_line("not a sudoku"));
+    }
+
+    #[test]
+    fn to_option_grid_round_trips() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let grid = board.to_option_grid();
+
+        assert_eq!(grid[0][..3], [None, Some(3), Some(4)]);
+        assert_eq!(Board::try_from(grid.concat()).unwrap(), board);
+    }
+
+    #[test]
+    fn to_char_grid_uses_the_empty_character() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let grid = board.to_char_grid('.');
+
+        assert_eq!(grid[0], ['.', '3', '4', '9', '.', '.', '.', '2', '8']);
+        assert_eq!(grid[8], ['9', '8', '.', '.', '.', '3', '6', '4', '.']);
+        assert_eq!(Board::empty().to_char_grid(' '), [[' '; 9]; 9]);
+    }
+
+    #[test]
+    fn board_id_is_stable() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+
+        assert_eq!(board.board_id(), 16_109_598_535_952_157_223);
+        assert_eq!(
+            board.first_solution().unwrap().board_id(),
+            4_383_092_169_156_586_880
+        );
+        assert_ne!(board.board_id(), Board::empty().board_id());
+    }
+
+    #[test]
+    fn as_bytes_round_trips() {
+        let board = Board::try_from(TEST_SUDOKU).unwrap();
+        let bytes = board.as_bytes();
+
+        assert_eq!(&bytes[0..9], b"034900028");
+        assert_eq!(Board::try_from(&bytes).unwrap(), board);
+    }
+
     #[test]
     fn to_string_full_cycle() {
         let board = Board::try_from(TEST_SUDOKU).unwrap();
@@ -0,0 +1,65 @@
+use crate::{board::Board, field::Field, position::Position, position_iter::PositionIter};
+
+/// Iterator over the fields of a board in row-major order, emitting each `Field`
+/// together with its `Position`.
+#[derive(Debug)]
+pub struct BoardIter<'a> {
+    board: &'a Board,
+    positions: PositionIter,
+}
+
+impl<'a> BoardIter<'a> {
+    /// Create a new BoardIter starting at the first board field
+    pub fn new(board: &'a Board) -> Self {
+        BoardIter {
+            board,
+            positions: PositionIter::from_first_field(),
+        }
+    }
+}
+
+impl Iterator for BoardIter<'_> {
+    type Item = (Position, Field);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next()
+            .map(|position| (position, *self.board.get_field(position)))
+    }
+}
+
+impl<'a> IntoIterator for &'a Board {
+    type Item = (Position, Field);
+    type IntoIter = BoardIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BoardIter::new(self)
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emits_fields_in_row_major_order() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+        let fields = (&board).into_iter().collect::<Vec<(Position, Field)>>();
+
+        assert_eq!(fields.len(), 81);
+        assert_eq!(fields[0], (Position { row: 0, column: 0 }, Field::empty()));
+        assert_eq!(
+            fields[1],
+            (Position { row: 0, column: 1 }, Field::new(3).unwrap())
+        );
+        assert_eq!(
+            fields[79],
+            (Position { row: 8, column: 7 }, Field::new(4).unwrap())
+        );
+
+        for (position, field) in &board {
+            assert_eq!(board.get_field(position), &field);
+        }
+    }
+}
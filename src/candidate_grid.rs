@@ -0,0 +1,339 @@
+use crate::{
+    board::Board, error::FieldParseError, field::Field, position::Position,
+    position_iter::PositionIter, unit::Unit,
+};
+
+/// Pencil marks for a `Board`, tracking the candidates of every empty field.
+///
+/// The candidates start out as the fields that can be put in each empty field
+/// without violating the sudoku rules. Logical techniques can then eliminate
+/// candidates that can be proven wrong, which is not possible on a `Board` alone.
+///
+/// The grid stays consistent with its board: placing a digit removes it from the
+/// candidates of all 20 fields sharing a row, column or box with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CandidateGrid {
+    board: Board,
+    /// Bitmask of candidates per field, where bit 0 is the digit 1 and bit 8 is the digit 9
+    masks: [[u16; 9]; 9],
+}
+
+impl CandidateGrid {
+    /// Create the pencil marks for a `Board`
+    pub fn from_board(board: &Board) -> Self {
+        let mut masks = [[0; 9]; 9];
+
+        for position in board.empty_positions() {
+            masks[position.row][position.column] = board
+                .candidates(position)
+                .iter()
+                .fold(0, |mask, field| mask | field_bit(field));
+        }
+
+        CandidateGrid {
+            board: *board,
+            masks,
+        }
+    }
+
+    /// Get the `Board` the pencil marks belong to
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Get the candidates for a `Position` in ascending order. A filled field has no candidates.
+    pub fn candidates(&self, position: Position) -> Vec<Field> {
+        let mask = self.mask(position);
+
+        Field::all()
+            .into_iter()
+            .filter(|field| mask & field_bit(field) != 0)
+            .collect()
+    }
+
+    /// Put a digit on the board and remove it from the candidates of every field in
+    /// the same row, column and box.
+    ///
+    /// Fails with `InvalidCharacter` if the digit is not 1-9, and with
+    /// `SudokuRuleViolation` if the digit is not a candidate for the position.
+    pub fn place(&mut self, position: Position, digit: u8) -> Result<(), FieldParseError> {
+        let field = Field::new(digit)?;
+        let bit = field_bit(&field);
+
+        if self.mask(position) & bit == 0 {
+            return Err(FieldParseError::SudokuRuleViolation);
+        }
+
+        self.board.put_field(position, field);
+        self.masks[position.row][position.column] = 0;
+
+        for unit in Unit::containing(position) {
+            for peer in unit.positions() {
+                self.eliminate(peer, bit);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Place every naked single, which is a field with one candidate, and every hidden
+    /// single, which is a digit with one place left in a unit. Returns whether any digit
+    /// was placed.
+    pub(crate) fn place_singles(&mut self) -> bool {
+        let mut singles = Vec::new();
+
+        for position in PositionIter::from_first_field() {
+            let mask = self.mask(position);
+
+            if mask.count_ones() == 1 {
+                singles.push((position, mask.trailing_zeros() as u8 + 1));
+            }
+        }
+
+        for unit in Unit::all() {
+            for field in Field::all() {
+                let bit = field_bit(&field);
+                let mut places = unit
+                    .positions()
+                    .into_iter()
+                    .filter(|position| self.mask(*position) & bit != 0);
+
+                if let (Some(position), None, Some(digit)) =
+                    (places.next(), places.next(), field.value())
+                {
+                    singles.push((position, digit));
+                }
+            }
+        }
+
+        // A single can be used up by placing another single first, which place rejects
+        singles
+            .into_iter()
+            .fold(false, |placed, (position, digit)| {
+                self.place(position, digit).is_ok() || placed
+            })
+    }
+
+    /// Eliminate candidates using naked and hidden pairs. Returns the number of
+    /// candidates eliminated.
+    ///
+    /// A naked pair is two fields in a unit with the same two candidates. Those two
+    /// digits must go in those two fields, so they are eliminated from the rest of the unit.
+    ///
+    /// A hidden pair is two digits that are only candidates in the same two fields of a
+    /// unit. Those two fields must hold those two digits, so all other candidates are
+    /// eliminated from them.
+    pub fn eliminate_pairs(&mut self) -> usize {
+        let mut eliminated = 0;
+
+        for unit in Unit::all() {
+            let positions = unit.positions();
+
+            // Naked pairs
+            for (i, first) in positions.iter().enumerate() {
+                let pair = self.mask(*first);
+
+                if pair.count_ones() != 2 {
+                    continue;
+                }
+
+                if let Some(second) = positions[i + 1..]
+                    .iter()
+                    .find(|pos| self.mask(**pos) == pair)
+                {
+                    for position in positions
+                        .iter()
+                        .filter(|pos| *pos != first && *pos != second)
+                    {
+                        eliminated += self.eliminate(*position, pair);
+                    }
+                }
+            }
+
+            // Hidden pairs
+            let places = Field::all().map(|field| {
+                positions
+                    .iter()
+                    .filter(|pos| self.mask(**pos) & field_bit(&field) != 0)
+                    .copied()
+                    .collect::<Vec<Position>>()
+            });
+
+            for first_digit in 0..9 {
+                for second_digit in first_digit + 1..9 {
+                    if places[first_digit].len() == 2 && places[first_digit] == places[second_digit]
+                    {
+                        let pair = (1 << first_digit) | (1 << second_digit);
+
+                        for position in &places[first_digit] {
+                            eliminated += self.eliminate(*position, !pair);
+                        }
+                    }
+                }
+            }
+        }
+
+        eliminated
+    }
+
+    /// Eliminate candidates using pointing pairs and box/line reduction. Returns the
+    /// number of candidates eliminated.
+    ///
+    /// When all candidates for a digit within a box lie in one row or column, the digit
+    /// must go in that box, so it is eliminated from the rest of the row or column.
+    /// Likewise, when all candidates for a digit within a row or column lie in one box,
+    /// the digit is eliminated from the rest of the box.
+    pub fn box_line_reduction(&mut self) -> usize {
+        let mut eliminated = 0;
+
+        for unit in Unit::all() {
+            let positions = unit.positions();
+
+            for field in Field::all() {
+                let bit = field_bit(&field);
+                let places = positions
+                    .iter()
+                    .filter(|pos| self.mask(**pos) & bit != 0)
+                    .copied()
+                    .collect::<Vec<Position>>();
+
+                let Some(first_place) = places.first() else {
+                    continue;
+                };
+
+                let intersecting_units = Unit::containing(*first_place)
+                    .into_iter()
+                    .filter(|other| matches!(unit, Unit::Box(_)) != matches!(other, Unit::Box(_)));
+
+                for other in intersecting_units {
+                    let other_positions = other.positions();
+
+                    if places.iter().all(|place| other_positions.contains(place)) {
+                        for position in other_positions
+                            .iter()
+                            .filter(|pos| !positions.contains(pos))
+                        {
+                            eliminated += self.eliminate(*position, bit);
+                        }
+                    }
+                }
+            }
+        }
+
+        eliminated
+    }
+
+    /// Get the candidate bitmask of a `Position`
+    fn mask(&self, position: Position) -> u16 {
+        self.masks[position.row][position.column]
+    }
+
+    /// Remove the candidates in a bitmask from a `Position`, returning how many were removed
+    fn eliminate(&mut self, position: Position, mask: u16) -> usize {
+        let before = self.mask(position);
+        let after = before & !mask;
+        self.masks[position.row][position.column] = after;
+
+        (before.count_ones() - after.count_ones()) as usize
+    }
+}
+
+/// Get the candidate bit of a filled `Field`
+fn field_bit(field: &Field) -> u16 {
+    field.value().map_or(0, |value| 1 << (value - 1))
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn starts_with_the_board_candidates() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let candidate_grid = CandidateGrid::from_board(&board);
+
+        for position in PositionIter::from_first_field() {
+            assert_eq!(
+                candidate_grid.candidates(position),
+                board.candidates(position)
+            );
+        }
+    }
+
+    #[test]
+    fn placing_a_digit_prunes_its_peers() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let mut candidate_grid = CandidateGrid::from_board(&board);
+        let position = Position { row: 0, column: 1 };
+
+        assert_eq!(
+            candidate_grid.place(position, 3),
+            Err(FieldParseError::SudokuRuleViolation)
+        );
+        assert_eq!(
+            candidate_grid.place(position, 0),
+            Err(FieldParseError::InvalidCharacter)
+        );
+
+        assert_eq!(candidate_grid.place(position, 1), Ok(()));
+        assert_eq!(
+            candidate_grid.board().get_field(position),
+            &Field::new(1).unwrap()
+        );
+        assert!(candidate_grid.candidates(position).is_empty());
+
+        // The grid matches the candidates of the board with the digit placed
+        for position in PositionIter::from_first_field() {
+            assert_eq!(
+                candidate_grid.candidates(position),
+                candidate_grid.board().candidates(position)
+            );
+        }
+    }
+
+    #[test]
+    fn placing_singles_solves_an_easy_board() {
+        let board = Board::try_from(include_str!("../sudokus/turbine.txt")).unwrap();
+        let mut candidate_grid = CandidateGrid::from_board(&board);
+
+        while candidate_grid.place_singles() {}
+
+        assert_eq!(candidate_grid.board(), &board.first_solution().unwrap());
+        assert!(!candidate_grid.place_singles());
+    }
+
+    #[test]
+    fn box_line_reduction_keeps_the_solution() {
+        let board = Board::try_from(include_str!("../sudokus/alien.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+        let mut candidate_grid = CandidateGrid::from_board(&board);
+
+        assert!(candidate_grid.box_line_reduction() > 0);
+        while candidate_grid.box_line_reduction() > 0 {}
+
+        for position in board.empty_positions() {
+            assert!(candidate_grid
+                .candidates(position)
+                .contains(solution.get_field(position)));
+        }
+    }
+
+    #[test]
+    fn eliminating_pairs_keeps_the_solution() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let solution = board.first_solution().unwrap();
+        let mut candidate_grid = CandidateGrid::from_board(&board);
+
+        let eliminated = candidate_grid.eliminate_pairs();
+
+        assert!(eliminated > 0);
+        assert_eq!(candidate_grid.eliminate_pairs(), 0);
+
+        for position in board.empty_positions() {
+            assert!(candidate_grid
+                .candidates(position)
+                .contains(solution.get_field(position)));
+        }
+    }
+}
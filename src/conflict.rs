@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use crate::{field::Field, position::Position, unit::Unit};
+
+/// The kind of unit a conflict is in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictKind {
+    /// The digit appears more than once in the row
+    Row,
+    /// The digit appears more than once in the column
+    Column,
+    /// The digit appears more than once in the 3x3 box
+    Box,
+}
+
+/// A digit on a board that breaks the sudoku rules, because it appears more than once in
+/// a row, column or box. A field can be in more than one conflict.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Conflict {
+    pub(crate) position: Position,
+    pub(crate) field: Field,
+    pub(crate) kind: ConflictKind,
+}
+
+impl Conflict {
+    /// Get the position of the conflicting field
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Get the conflicting field
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Get the kind of unit the digit appears more than once in
+    pub fn kind(&self) -> ConflictKind {
+        self.kind
+    }
+}
+
+/// Describe the `Conflict` for humans, e.g. "7 appears twice in column 3"
+impl Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = match self.kind {
+            ConflictKind::Row => Unit::Row(self.position.row),
+            ConflictKind::Column => Unit::Column(self.position.column),
+            ConflictKind::Box => Unit::Box(self.position.box_index()),
+        };
+
+        write!(f, "{} appears twice in {}", self.field, unit)
+    }
+}
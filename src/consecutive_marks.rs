@@ -0,0 +1,182 @@
+use crate::{error::SudokuConstraintError, field::Field, position::Position, Board};
+
+/// The marks of a consecutive sudoku, placed on the borders between adjacent fields
+/// whose digits differ by exactly 1. Borders without a mark are just as strict: the
+/// digits on either side must not differ by 1.
+///
+/// Solve a board with consecutive marks using `Board::solve_iter_consecutive`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConsecutiveMarks {
+    /// Bitmask of marks on the right border of a field, where bit n is the field with
+    /// index n
+    right: u128,
+    /// Bitmask of marks on the bottom border of a field, where bit n is the field with
+    /// index n
+    below: u128,
+}
+
+impl ConsecutiveMarks {
+    /// Create consecutive marks without any marks, so no adjacent digits may differ by 1
+    pub fn new() -> Self {
+        ConsecutiveMarks::default()
+    }
+
+    /// Mark the border between two fields, requiring their digits to differ by exactly 1.
+    /// Fails with `NotAdjacent` if the fields don't share a side.
+    pub fn mark(&mut self, a: Position, b: Position) -> Result<&mut Self, SudokuConstraintError> {
+        if !a.is_adjacent_to(b) {
+            return Err(SudokuConstraintError::NotAdjacent);
+        }
+
+        let first = if a.to_index() < b.to_index() { a } else { b };
+
+        if a.row == b.row {
+            self.right |= 1 << first.to_index();
+        } else {
+            self.below |= 1 << first.to_index();
+        }
+
+        Ok(self)
+    }
+
+    /// Is the border between two adjacent fields marked?
+    fn is_marked(&self, a: Position, b: Position) -> bool {
+        let first = if a.to_index() < b.to_index() { a } else { b };
+        let mask = if a.row == b.row {
+            self.right
+        } else {
+            self.below
+        };
+
+        mask & 1 << first.to_index() != 0
+    }
+
+    /// Can the field be put at the position without breaking the marks with a filled
+    /// adjacent field on the board?
+    pub(crate) fn allows(&self, board: &Board, position: Position, field: Field) -> bool {
+        let Some(value) = field.value() else {
+            return true;
+        };
+
+        neighbours(position).all(|neighbour| {
+            board.get_field(neighbour).value().is_none_or(|other| {
+                (value.abs_diff(other) == 1) == self.is_marked(position, neighbour)
+            })
+        })
+    }
+}
+
+/// Iterate the positions sharing a side with a position
+fn neighbours(position: Position) -> impl Iterator<Item = Position> {
+    let Position { row, column } = position;
+
+    [
+        row.checked_sub(1).map(|row| Position { row, column }),
+        (row < 8).then(|| Position {
+            row: row + 1,
+            column,
+        }),
+        column.checked_sub(1).map(|column| Position { row, column }),
+        (column < 8).then(|| Position {
+            row,
+            column: column + 1,
+        }),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position_iter::PositionIter;
+
+    /// Get the marks of every pair of adjacent fields in a solved board that differ by 1
+    fn marks_of(solution: &Board) -> ConsecutiveMarks {
+        let mut marks = ConsecutiveMarks::new();
+
+        for position in PositionIter::from_first_field() {
+            for neighbour in neighbours(position) {
+                let value = solution.get_field(position).value().unwrap();
+                let other = solution.get_field(neighbour).value().unwrap();
+
+                if value.abs_diff(other) == 1 {
+                    marks.mark(position, neighbour).unwrap();
+                }
+            }
+        }
+
+        marks
+    }
+
+    #[test]
+    fn solves_a_consecutive_sudoku() {
+        let solution = Board::try_from(include_str!("../sudokus/starry.txt"))
+            .unwrap()
+            .first_solution()
+            .unwrap();
+
+        // The top three rows of the "sudokus/starry.txt" board, which has a huge number of
+        // solutions without the marks
+        let board = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------",
+        )
+        .unwrap();
+
+        let solutions = board
+            .solve_iter_consecutive(&marks_of(&solution))
+            .filter(|(_, is_solved)| *is_solved)
+            .map(|(board, _)| board)
+            .collect::<Vec<Board>>();
+
+        assert_eq!(solutions, vec![solution]);
+    }
+
+    #[test]
+    fn givens_breaking_a_border_have_no_solution() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let mut marks = marks_of(&board.first_solution().unwrap());
+
+        assert!(board.solve_iter_consecutive(&marks).next().is_some());
+
+        // The 4 and 2 in the second row differ by 2
+        marks
+            .mark(
+                Position { row: 1, column: 1 },
+                Position { row: 1, column: 2 },
+            )
+            .unwrap();
+
+        assert_eq!(board.solve_iter_consecutive(&marks).next(), None);
+    }
+
+    #[test]
+    fn marks_are_between_adjacent_fields_in_either_order() {
+        let mut marks = ConsecutiveMarks::new();
+        let a = Position { row: 4, column: 4 };
+        let b = Position { row: 4, column: 5 };
+        let c = Position { row: 5, column: 4 };
+
+        marks.mark(b, a).unwrap();
+
+        assert!(marks.is_marked(a, b));
+        assert!(!marks.is_marked(a, c));
+
+        marks.mark(a, c).unwrap();
+
+        assert!(marks.is_marked(c, a));
+        assert_eq!(
+            marks.mark(b, c).err(),
+            Some(SudokuConstraintError::NotAdjacent)
+        );
+    }
+}
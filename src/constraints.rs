@@ -0,0 +1,198 @@
+use crate::{
+    consecutive_marks::ConsecutiveMarks, field::Field, inequalities::Inequalities,
+    position::Position, position_iter::PositionIter, Board,
+};
+
+/// Extra constraints on top of the sudoku rules, for solving variant sudokus. The
+/// constraints can be combined freely, e.g. a miracle sudoku is anti-knight, anti-king
+/// and non-consecutive.
+///
+/// Solve a board with constraints using `Board::solve_iter_with`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constraints {
+    rules: Vec<Rule>,
+}
+
+/// A single extra constraint
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Rule {
+    Diagonals,
+    AntiKnight,
+    AntiKing,
+    Consecutive(ConsecutiveMarks),
+    Inequalities(Inequalities),
+}
+
+impl Constraints {
+    /// Create constraints without any extra constraints, solving like a normal sudoku
+    pub fn new() -> Self {
+        Constraints::default()
+    }
+
+    /// Digits may not repeat on either of the two main diagonals, as in an X-sudoku
+    pub fn with_diagonals(mut self) -> Self {
+        self.rules.push(Rule::Diagonals);
+        self
+    }
+
+    /// The same digit may not appear a chess knight's move apart
+    pub fn with_anti_knight(mut self) -> Self {
+        self.rules.push(Rule::AntiKnight);
+        self
+    }
+
+    /// The same digit may not appear in diagonally adjacent fields
+    pub fn with_anti_king(mut self) -> Self {
+        self.rules.push(Rule::AntiKing);
+        self
+    }
+
+    /// Adjacent digits must differ by exactly 1 across the marked borders, and must not
+    /// across any other border
+    pub fn with_consecutive_marks(mut self, marks: ConsecutiveMarks) -> Self {
+        self.rules.push(Rule::Consecutive(marks));
+        self
+    }
+
+    /// Adjacent digits may never differ by exactly 1. This is consecutive marks without
+    /// any marks.
+    pub fn with_non_consecutive(self) -> Self {
+        self.with_consecutive_marks(ConsecutiveMarks::new())
+    }
+
+    /// Adjacent digits must respect the inequality signs between them
+    pub fn with_inequalities(mut self, inequalities: Inequalities) -> Self {
+        self.rules.push(Rule::Inequalities(inequalities));
+        self
+    }
+
+    /// Can the field be put at the position without breaking a constraint with the
+    /// other filled fields on the board?
+    pub(crate) fn allows(&self, board: &Board, position: Position, field: Field) -> bool {
+        self.rules.iter().all(|rule| match rule {
+            Rule::Diagonals => differs_from_all(board, diagonal_peers(position), &field),
+            Rule::AntiKnight => differs_from_all(board, position.knight_moves(), &field),
+            Rule::AntiKing => differs_from_all(board, position.diagonal_neighbours(), &field),
+            Rule::Consecutive(marks) => marks.allows(board, position, field),
+            Rule::Inequalities(inequalities) => inequalities.allows(board, position, field),
+        })
+    }
+
+    /// Do the filled fields of the board respect every constraint?
+    pub(crate) fn are_satisfied_by(&self, board: &Board) -> bool {
+        PositionIter::from_first_field().all(|position| {
+            let field = *board.get_field(position);
+            field.is_empty() || self.allows(board, position, field)
+        })
+    }
+}
+
+/// Iterate the other positions on the main diagonals through a position, if any
+fn diagonal_peers(position: Position) -> impl Iterator<Item = Position> {
+    let on_diagonal = position.row == position.column;
+    let on_anti_diagonal = position.row + position.column == 8;
+
+    (0..9)
+        .flat_map(move |index| {
+            [
+                on_diagonal.then_some(Position {
+                    row: index,
+                    column: index,
+                }),
+                on_anti_diagonal.then_some(Position {
+                    row: index,
+                    column: 8 - index,
+                }),
+            ]
+        })
+        .flatten()
+        .filter(move |peer| *peer != position)
+}
+
+/// Is the field different from the fields at all the positions?
+fn differs_from_all(
+    board: &Board,
+    mut positions: impl Iterator<Item = Position>,
+    field: &Field,
+) -> bool {
+    positions.all(|position| board.get_field(position) != field)
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn solutions(board: Board, constraints: Constraints) -> Vec<Board> {
+        board
+            .solve_iter_with(constraints)
+            .filter(|(_, is_solved)| *is_solved)
+            .map(|(board, _)| board)
+            .collect()
+    }
+
+    #[test]
+    fn solves_a_miracle_sudoku() {
+        // The miracle sudoku by Mitchell Lee, which only has the two givens in the middle.
+        // The top row of the solution is given as well to keep the search short.
+        let board = Board::try_from(
+            "483726159
+             ---------
+             ---------
+             ---------
+             --1------
+             ------2--
+             ---------
+             ---------
+             ---------",
+        )
+        .unwrap();
+
+        let miracle = Constraints::new()
+            .with_anti_knight()
+            .with_anti_king()
+            .with_non_consecutive();
+
+        let expected = Board::try_from(
+            "483726159
+             726159483
+             159483726
+             837261594
+             261594837
+             594837261
+             372615948
+             615948372
+             948372615",
+        )
+        .unwrap();
+
+        assert_eq!(solutions(board, miracle), vec![expected]);
+    }
+
+    #[test]
+    fn keeps_the_diagonals_unique() {
+        let (solution, _) = Board::empty()
+            .solve_iter_with(Constraints::new().with_diagonals())
+            .find(|(_, is_solved)| *is_solved)
+            .unwrap();
+
+        let diagonal = (0..9)
+            .map(|index| *solution.get_field(Position::from_index(index * 10).unwrap()))
+            .collect::<std::collections::HashSet<Field>>();
+        let anti_diagonal = (1..10)
+            .map(|index| *solution.get_field(Position::from_index(index * 8).unwrap()))
+            .collect::<std::collections::HashSet<Field>>();
+
+        assert_eq!(diagonal.len(), 9);
+        assert_eq!(anti_diagonal.len(), 9);
+    }
+
+    #[test]
+    fn without_constraints_solves_like_a_normal_sudoku() {
+        let board = Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap();
+
+        assert!(board
+            .solve_iter_with(Constraints::new())
+            .eq(board.solve_iter()));
+    }
+}
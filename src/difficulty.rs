@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+/// How hard a sudoku is for a human, rated by the logical techniques needed to solve it
+/// without guessing
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Difficulty {
+    /// Solvable with naked and hidden singles
+    Easy,
+    /// Also needs pointing pairs and box/line reduction
+    Medium,
+    /// Also needs naked and hidden pairs
+    Hard,
+    /// Can't be solved with the techniques above, so guessing is needed
+    Expert,
+}
+
+/// Render the `Difficulty` in lowercase, e.g. "expert"
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Medium => write!(f, "medium"),
+            Difficulty::Hard => write!(f, "hard"),
+            Difficulty::Expert => write!(f, "expert"),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn displays_in_lowercase() {
+        assert_eq!(Difficulty::Easy.to_string(), "easy");
+        assert_eq!(Difficulty::Expert.to_string(), "expert");
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn serializes_like_display() {
+        assert_eq!(
+            serde_json::to_string(&Difficulty::Medium).unwrap(),
+            r#""medium""#
+        );
+    }
+}
@@ -2,17 +2,54 @@
 
 use std::collections::HashSet;
 
-use crate::Position;
+use crate::{board::write_grid, Board, Field, Position};
 
 /// Sudoku board parse-errors
 #[derive(Debug, PartialEq)]
 pub enum SudokuParseError {
     /// Input does not have length 81
     InvalidLength,
+    /// Input is not in the expected format
+    InvalidFormat,
     /// The Sudoku has parse errors
     ParseErrors(HashSet<(Position, FieldParseError)>),
 }
 
+impl SudokuParseError {
+    /// Render the original input as a grid with every field that failed to parse
+    /// marked with an `X`. Useful for pinpointing where a malformed sudoku is broken.
+    ///
+    /// For `InvalidLength` and `InvalidFormat` there is no grid to show, so the error
+    /// message is returned.
+    pub fn render_on(&self, input: &str) -> String {
+        match self {
+            SudokuParseError::InvalidLength | SudokuParseError::InvalidFormat => self.to_string(),
+            SudokuParseError::ParseErrors(errors) => {
+                let characters = input.split_whitespace().collect::<String>();
+                let characters = characters.chars().collect::<Vec<char>>();
+                let mut output = String::new();
+
+                let _ = write_grid(&mut output, |position| {
+                    if errors
+                        .iter()
+                        .any(|(error_position, _)| *error_position == position)
+                    {
+                        return 'X';
+                    }
+
+                    characters
+                        .get(position.to_index())
+                        .map_or(' ', |character| {
+                            Field::from_char(*character).map_or(*character, |field| field.to_char())
+                        })
+                });
+
+                output
+            }
+        }
+    }
+}
+
 impl std::error::Error for SudokuParseError {}
 
 impl std::fmt::Display for SudokuParseError {
@@ -23,6 +60,7 @@ impl std::fmt::Display for SudokuParseError {
                 "Sudoku has invalid fields or fields that violate the sudoku rules"
             ),
             SudokuParseError::InvalidLength => write!(f, "Input does not have length 81"),
+            SudokuParseError::InvalidFormat => write!(f, "Input is not in the expected format"),
         }
     }
 }
@@ -50,8 +88,18 @@ impl std::fmt::Display for FieldParseError {
 /// Errors from solving a sudoku
 #[derive(Debug, PartialEq)]
 pub enum SudokuSolveError {
-    /// The sudoku does not have a solution
-    Unsolvable,
+    /// The sudoku does not have a solution, because the empty field at the position has
+    /// no candidates. This is detected before searching.
+    Contradiction {
+        /// The position of the field without candidates
+        position: Position,
+    },
+    /// The sudoku does not have a solution, as the search tried every possibility
+    Exhausted,
+    /// The sudoku has more than one solution
+    MultipleSolutions,
+    /// Solving took longer than the deadline
+    TimedOut,
 }
 
 impl std::error::Error for SudokuSolveError {}
@@ -59,7 +107,167 @@ impl std::error::Error for SudokuSolveError {}
 impl std::fmt::Display for SudokuSolveError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            SudokuSolveError::Unsolvable => write!(f, "The sudoku is unsolvable"),
+            SudokuSolveError::Contradiction { position } => write!(
+                f,
+                "The sudoku is unsolvable, as no digit fits in {position}"
+            ),
+            SudokuSolveError::Exhausted => {
+                write!(
+                    f,
+                    "The sudoku is unsolvable, as every possibility was tried"
+                )
+            }
+            SudokuSolveError::MultipleSolutions => write!(f, "The sudoku has multiple solutions"),
+            SudokuSolveError::TimedOut => write!(f, "Solving the sudoku timed out"),
+        }
+    }
+}
+
+/// Errors from transforming a sudoku
+#[derive(Debug, PartialEq)]
+pub enum SudokuTransformError {
+    /// A band, stack, row or column index is out of range
+    InvalidIndex,
+    /// The two rows are in different bands
+    NotInSameBand,
+    /// The two columns are in different stacks
+    NotInSameStack,
+}
+
+impl std::error::Error for SudokuTransformError {}
+
+impl std::fmt::Display for SudokuTransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SudokuTransformError::InvalidIndex => write!(f, "The index is out of range"),
+            SudokuTransformError::NotInSameBand => write!(f, "The rows are in different bands"),
+            SudokuTransformError::NotInSameStack => {
+                write!(f, "The columns are in different stacks")
+            }
+        }
+    }
+}
+
+/// Errors from generating a sudoku
+#[derive(Debug, PartialEq)]
+pub enum SudokuGenerateError {
+    /// Fewer clues than `Board::MIN_CLUES` were asked for
+    TooFewClues,
+}
+
+impl std::error::Error for SudokuGenerateError {}
+
+impl std::fmt::Display for SudokuGenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SudokuGenerateError::TooFewClues => write!(
+                f,
+                "A sudoku with a unique solution needs at least {} clues",
+                Board::MIN_CLUES
+            ),
+        }
+    }
+}
+
+/// Errors from adding an extra constraint to a sudoku
+#[derive(Debug, PartialEq)]
+pub enum SudokuConstraintError {
+    /// The constraint is between two fields that don't share a side
+    NotAdjacent,
+}
+
+impl std::error::Error for SudokuConstraintError {}
+
+impl std::fmt::Display for SudokuConstraintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SudokuConstraintError::NotAdjacent => write!(f, "The fields are not adjacent"),
+        }
+    }
+}
+
+/// Errors from loading a sudoku from a file
+#[derive(Debug)]
+pub enum BoardFileError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The contents of the file is not a valid sudoku
+    Parse(SudokuParseError),
+}
+
+impl std::error::Error for BoardFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BoardFileError::Io(error) => Some(error),
+            BoardFileError::Parse(error) => Some(error),
         }
     }
 }
+
+impl std::fmt::Display for BoardFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BoardFileError::Io(error) => write!(f, "Could not read the sudoku file: {error}"),
+            BoardFileError::Parse(error) => write!(f, "Could not parse the sudoku file: {error}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for BoardFileError {
+    fn from(error: std::io::Error) -> Self {
+        BoardFileError::Io(error)
+    }
+}
+
+impl From<SudokuParseError> for BoardFileError {
+    fn from(error: SudokuParseError) -> Self {
+        BoardFileError::Parse(error)
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_parse_errors_on_the_input_grid() {
+        // The "sudokus/oneeighty.txt" board with a duplicate 3 and an invalid character
+        let input = "-349--328
+                     2-------6
+                     ---271---
+                     -----2-6-
+                     45-----39
+                     -6-4-----
+                     ---614--f
+                     3-------1
+                     98---364-";
+
+        let error = Board::try_from(input).err().unwrap();
+
+        let expected = "+-----------+
+| X4|9  |X28|
+|2  |   |  6|
+|   |271|   |
++---+---+---+
+|   |  2| 6 |
+|45 |   | 39|
+| 6 |4  |   |
++---+---+---+
+|   |614|  X|
+|3  |   |  1|
+|98 |  3|64 |
++-----------+
+";
+
+        assert_eq!(error.render_on(input), expected);
+    }
+
+    #[test]
+    fn too_few_clues_names_the_minimum() {
+        assert_eq!(
+            SudokuGenerateError::TooFewClues.to_string(),
+            "A sudoku with a unique solution needs at least 17 clues"
+        );
+    }
+}
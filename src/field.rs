@@ -5,10 +5,13 @@ use crate::error::FieldParseError;
 /// Represents a field value on a sudoku board.
 ///
 /// The `Field` can either have a value of 1-9, or be empty.
-#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+///
+/// Fields are ordered by their digit, and empty fields sort after all filled fields.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
 pub struct Field(FieldInner);
 
-#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+// The order of the variants decides the ordering of fields, so `Empty` must stay last
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
 enum FieldInner {
     /// A filled in sudoku field with a value 1-9
     Value(u8),
@@ -22,8 +25,10 @@ impl Field {
         Field(FieldInner::Empty)
     }
 
-    /// Create a `Field` with value. Does not validate the value
+    /// Create a `Field` with value. Does not validate the value in release builds, so
+    /// callers must only pass digits 1-9
     pub(crate) fn from_u8(value: u8) -> Self {
+        debug_assert!((1..=9).contains(&value), "invalid field value {value}");
         Field(FieldInner::Value(value))
     }
 
@@ -36,6 +41,36 @@ impl Field {
         }
     }
 
+    /// Create a `Field` from a digit 1-9, failing with `InvalidCharacter` for any other
+    /// value. This is the same as `Field::new`.
+    pub fn try_new(digit: u8) -> Result<Field, FieldParseError> {
+        Field::new(digit)
+    }
+
+    /// Get all nine filled `Field`s, in order from 1 to 9
+    pub fn all() -> [Field; 9] {
+        [1, 2, 3, 4, 5, 6, 7, 8, 9].map(Field::from_u8)
+    }
+
+    /// Create a `Field` from a character. The digits `1`-`9` become filled fields, while
+    /// `-`, `.`, `0` and space become empty fields.
+    pub fn from_char(character: char) -> Result<Field, FieldParseError> {
+        match character {
+            '-' | '.' | '0' | ' ' => Ok(Field::empty()),
+            '1'..='9' => Field::new(character as u8 - b'0'),
+            _ => Err(FieldParseError::InvalidCharacter),
+        }
+    }
+
+    /// Get the character for a `Field`, which is its digit or a space if empty.
+    /// This matches how a `Field` is displayed.
+    pub fn to_char(&self) -> char {
+        match self.0 {
+            FieldInner::Value(value) => char::from(b'0' + value),
+            FieldInner::Empty => ' ',
+        }
+    }
+
     /// Get the value of a `Field` if set, otherwise `None` if empty
     pub fn value(&self) -> Option<u8> {
         match self.0 {
@@ -58,9 +93,67 @@ impl Field {
 /// Render a `Field` as a `String`
 impl Display for Field {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Field(FieldInner::Empty) => f.write_char(' '),
-            Field(FieldInner::Value(val)) => f.write_str(&format!("{val}")),
+        f.write_char(self.to_char())
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_creates_fields_from_digits() {
+        for digit in 1..=9 {
+            assert_eq!(Field::try_new(digit).unwrap().value(), Some(digit));
+        }
+
+        for invalid in [0, 10, 255] {
+            assert_eq!(
+                Field::try_new(invalid),
+                Err(FieldParseError::InvalidCharacter)
+            );
+        }
+    }
+
+    #[test]
+    fn orders_by_digit_with_empty_fields_last() {
+        let mut fields = vec![
+            Field::empty(),
+            Field::from_u8(9),
+            Field::from_u8(1),
+            Field::empty(),
+            Field::from_u8(5),
+        ];
+        fields.sort();
+
+        assert_eq!(
+            fields,
+            vec![
+                Field::from_u8(1),
+                Field::from_u8(5),
+                Field::from_u8(9),
+                Field::empty(),
+                Field::empty()
+            ]
+        );
+        assert!(Field::from_u8(9) < Field::empty());
+    }
+
+    #[test]
+    fn converts_to_and_from_chars() {
+        for field in Field::all() {
+            assert_eq!(Field::from_char(field.to_char()), Ok(field));
         }
+
+        for character in ['-', '.', '0', ' '] {
+            assert_eq!(Field::from_char(character), Ok(Field::empty()));
+        }
+
+        assert_eq!(Field::empty().to_char(), ' ');
+        assert_eq!(
+            Field::from_char('x'),
+            Err(FieldParseError::InvalidCharacter)
+        );
     }
 }
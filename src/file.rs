@@ -0,0 +1,45 @@
+//! Loading boards from files
+
+use std::{fs, path::Path};
+
+use crate::{error::BoardFileError, Board};
+
+impl Board {
+    /// Create a `Board` from a text file, in any format accepted by `Board::try_from(&str)`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Board, BoardFileError> {
+        let input = fs::read_to_string(path)?;
+
+        Ok(Board::try_from(input)?)
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SudokuParseError;
+
+    #[test]
+    fn loads_a_board_from_a_file() {
+        let board = Board::from_file("sudokus/starry.txt").unwrap();
+
+        assert_eq!(
+            board,
+            Board::try_from(include_str!("../sudokus/starry.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn fails_on_missing_and_invalid_files() {
+        assert!(matches!(
+            Board::from_file("sudokus/missing.txt"),
+            Err(BoardFileError::Io(_))
+        ));
+
+        // The samurai file is not a single board
+        assert!(matches!(
+            Board::from_file("sudokus/samurai.txt"),
+            Err(BoardFileError::Parse(SudokuParseError::InvalidLength))
+        ));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::position::Position;
+
+/// The result of checking a `Board` against its solution, counting the correct, incorrect
+/// and empty fields. Created with `Board::grade_against`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grade {
+    pub(crate) correct: usize,
+    pub(crate) incorrect: Vec<Position>,
+    pub(crate) empty: usize,
+}
+
+impl Grade {
+    /// Get the number of filled fields matching the solution
+    pub fn correct(&self) -> usize {
+        self.correct
+    }
+
+    /// Get the number of filled fields not matching the solution
+    pub fn incorrect(&self) -> usize {
+        self.incorrect.len()
+    }
+
+    /// Get the positions of the filled fields not matching the solution in row-major order
+    pub fn incorrect_positions(&self) -> &[Position] {
+        &self.incorrect
+    }
+
+    /// Get the number of empty fields
+    pub fn empty(&self) -> usize {
+        self.empty
+    }
+
+    /// Is the board completely and correctly filled?
+    pub fn is_solved(&self) -> bool {
+        self.incorrect.is_empty() && self.empty == 0
+    }
+}
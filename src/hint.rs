@@ -0,0 +1,41 @@
+use crate::{field::Field, position::Position};
+
+/// A logical solving technique
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Technique {
+    /// The field has only one candidate left
+    NakedSingle,
+    /// The digit has only one place left in a row, column or box
+    HiddenSingle,
+}
+
+/// The next logical move on a board, explaining which `Field` to put where and why
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hint {
+    pub(crate) technique: Technique,
+    pub(crate) position: Position,
+    pub(crate) field: Field,
+    pub(crate) reason: String,
+}
+
+impl Hint {
+    /// Get the technique used to find the move
+    pub fn technique(&self) -> Technique {
+        self.technique
+    }
+
+    /// Get the position to put the field at
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Get the field to put on the board
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Get a short human readable reason for the move
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
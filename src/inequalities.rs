@@ -0,0 +1,147 @@
+use crate::{error::SudokuConstraintError, field::Field, position::Position, Board};
+
+/// Inequality signs between adjacent fields of a greater-than sudoku. Besides the usual
+/// sudoku rules, the digits on either side of a sign must respect it.
+///
+/// Solve a board with inequalities using `Board::solve_iter_inequalities`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inequalities {
+    /// Pairs of positions where the first field must be smaller than the second
+    less_than: Vec<(Position, Position)>,
+}
+
+impl Inequalities {
+    /// Create a set of inequalities without any signs
+    pub fn new() -> Self {
+        Inequalities::default()
+    }
+
+    /// Require the field at `smaller` to be less than the field at `larger`. Fails with
+    /// `NotAdjacent` if the fields don't share a side.
+    pub fn less_than(
+        &mut self,
+        smaller: Position,
+        larger: Position,
+    ) -> Result<&mut Self, SudokuConstraintError> {
+        if !smaller.is_adjacent_to(larger) {
+            return Err(SudokuConstraintError::NotAdjacent);
+        }
+
+        self.less_than.push((smaller, larger));
+        Ok(self)
+    }
+
+    /// Require the field at `larger` to be greater than the field at `smaller`. Fails with
+    /// `NotAdjacent` if the fields don't share a side.
+    pub fn greater_than(
+        &mut self,
+        larger: Position,
+        smaller: Position,
+    ) -> Result<&mut Self, SudokuConstraintError> {
+        self.less_than(smaller, larger)
+    }
+
+    /// Can the field be put at the position without breaking an inequality with a filled
+    /// field on the board?
+    pub(crate) fn allows(&self, board: &Board, position: Position, field: Field) -> bool {
+        self.less_than.iter().all(|(smaller, larger)| {
+            match (
+                board.get_field(*smaller).value(),
+                board.get_field(*larger).value(),
+            ) {
+                (Some(smaller_value), _) if *larger == position => {
+                    field.value().is_some_and(|value| smaller_value < value)
+                }
+                (_, Some(larger_value)) if *smaller == position => {
+                    field.value().is_some_and(|value| value < larger_value)
+                }
+                _ => true,
+            }
+        })
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The "sudokus/oneeighty.txt" board with two solutions, differing in the last two
+    // fields of the third and the last row
+    const TWO_SOLUTIONS: &str = "-349---28
+                                 2-------6
+                                 ---271---
+                                 -----2-6-
+                                 45-----39
+                                 -6-4-----
+                                 ---614---
+                                 3-------1
+                                 98---36--";
+
+    fn solutions(board: Board, inequalities: &Inequalities) -> Vec<Board> {
+        board
+            .solve_iter_inequalities(inequalities)
+            .filter(|(_, is_solved)| *is_solved)
+            .map(|(board, _)| board)
+            .collect()
+    }
+
+    #[test]
+    fn picks_the_solution_respecting_the_signs() {
+        let board = Board::try_from(TWO_SOLUTIONS).unwrap();
+        let last = Position::from_index(80).unwrap();
+        let before_last = Position::from_index(79).unwrap();
+
+        assert_eq!(solutions(board, &Inequalities::new()).len(), 2);
+
+        let mut inequalities = Inequalities::new();
+        inequalities.less_than(before_last, last).unwrap();
+        let ascending = solutions(board, &inequalities);
+
+        assert_eq!(ascending.len(), 1);
+        assert_eq!(ascending[0].get_field(last), &Field::from_u8(5));
+
+        let mut inequalities = Inequalities::new();
+        inequalities.greater_than(before_last, last).unwrap();
+        let descending = solutions(board, &inequalities);
+
+        assert_eq!(descending.len(), 1);
+        assert_eq!(descending[0].get_field(last), &Field::from_u8(4));
+    }
+
+    #[test]
+    fn givens_breaking_a_sign_have_no_solution() {
+        let board = Board::try_from(TWO_SOLUTIONS).unwrap();
+        let mut inequalities = Inequalities::new();
+
+        // The givens are 3 and 4
+        inequalities
+            .greater_than(
+                Position::from_index(1).unwrap(),
+                Position::from_index(2).unwrap(),
+            )
+            .unwrap();
+
+        assert!(solutions(board, &inequalities).is_empty());
+    }
+
+    #[test]
+    fn only_allows_signs_between_adjacent_fields() {
+        let mut inequalities = Inequalities::new();
+        let first = Position::from_index(0).unwrap();
+
+        assert_eq!(
+            inequalities
+                .less_than(first, Position::from_index(10).unwrap())
+                .err(),
+            Some(SudokuConstraintError::NotAdjacent)
+        );
+        assert_eq!(
+            inequalities.less_than(first, first).err(),
+            Some(SudokuConstraintError::NotAdjacent)
+        );
+        assert!(inequalities
+            .less_than(first, Position::from_index(9).unwrap())
+            .is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+//! JSON input and output for boards, enabled by the `json` feature.
+//!
+//! A board is represented as an object with a `cells` array of nine rows, each holding
+//! nine numbers where 0 is an empty field:
+//!
+//! ```json
+//! { "cells": [[0, 3, 4, 9, 0, 0, 0, 2, 8], ...] }
+//! ```
+
+use serde_json::{json, Value};
+
+use crate::{error::SudokuParseError, Board};
+
+impl Board {
+    /// Create a `Board` from JSON in the `{ "cells": [[...], ...] }` format.
+    ///
+    /// Malformed JSON fails with `InvalidFormat`, and rows or columns not of length 9 with
+    /// `InvalidLength`. The cells are then validated like any other parsed board.
+    pub fn from_json(input: &str) -> Result<Board, SudokuParseError> {
+        let value: Value =
+            serde_json::from_str(input).map_err(|_| SudokuParseError::InvalidFormat)?;
+
+        let rows = value
+            .get("cells")
+            .and_then(Value::as_array)
+            .ok_or(SudokuParseError::InvalidFormat)?;
+
+        if rows.len() != 9 {
+            return Err(SudokuParseError::InvalidLength);
+        }
+
+        let mut fields = Vec::with_capacity(81);
+
+        for row in rows {
+            let row = row.as_array().ok_or(SudokuParseError::InvalidFormat)?;
+
+            if row.len() != 9 {
+                return Err(SudokuParseError::InvalidLength);
+            }
+
+            fields.extend(row.iter().map(|cell| match cell.as_u64() {
+                Some(0) => None,
+                // Use an invalid field value for anything else which will fail when parsing
+                Some(value) => Some(u8::try_from(value).unwrap_or(u8::MAX)),
+                None => Some(u8::MAX),
+            }));
+        }
+
+        Board::try_from(fields)
+    }
+
+    /// Get the `Board` as JSON in the `{ "cells": [[...], ...] }` format, with 0 for
+    /// empty fields
+    pub fn to_json(&self) -> String {
+        json!({ "cells": self.to_grid() }).to_string()
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{FieldParseError, Position};
+
+    const TEST_JSON: &str = r#"{"cells":[[0,3,4,9,0,0,0,2,8],[2,0,0,0,0,0,0,0,6],[0,0,0,2,7,1,0,0,0],[0,0,0,0,0,2,0,6,0],[4,5,0,0,0,0,0,3,9],[0,6,0,4,0,0,0,0,0],[0,0,0,6,1,4,0,0,0],[3,0,0,0,0,0,0,0,1],[9,8,0,0,0,3,6,4,0]]}"#;
+
+    #[test]
+    fn round_trips_json() {
+        let board = Board::from_json(TEST_JSON).unwrap();
+
+        assert_eq!(
+            board,
+            Board::try_from(include_str!("../sudokus/oneeighty.txt")).unwrap()
+        );
+        assert_eq!(board.to_json(), TEST_JSON);
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        assert_eq!(
+            Board::from_json("{\"cells\": ").err(),
+            Some(SudokuParseError::InvalidFormat)
+        );
+        assert_eq!(
+            Board::from_json("{\"rows\": []}").err(),
+            Some(SudokuParseError::InvalidFormat)
+        );
+        assert_eq!(
+            Board::from_json("{\"cells\": [[1, 2, 3]]}").err(),
+            Some(SudokuParseError::InvalidLength)
+        );
+
+        let out_of_range = TEST_JSON.replacen("[0,3,4", "[10,3,4", 1);
+        let expected_errors = [(
+            Position { row: 0, column: 0 },
+            FieldParseError::InvalidCharacter,
+        )]
+        .into_iter()
+        .collect::<HashSet<_>>();
+
+        assert_eq!(
+            Board::from_json(&out_of_range).err(),
+            Some(SudokuParseError::ParseErrors(expected_errors))
+        );
+    }
+}
@@ -32,6 +32,18 @@
 //! cargo run --example cli -- sudokus/oneeighty.txt --display --delay 5
 //! ```
 //!
+//! For highlighting the given clues in the solution, and any fields that fail to parse:
+//!
+//! ```text
+//! cargo run --example cli -- sudokus/oneeighty.txt --highlight
+//! ```
+//!
+//! For writing every step of the solution to numbered files in a `frames` directory:
+//!
+//! ```text
+//! cargo run --example cli -- sudokus/oneeighty.txt --frames frames
+//! ```
+//!
 //! ## Usage
 //!
 //! ### Get the first solution of a sudoku
@@ -256,20 +268,61 @@
     unused
 )]
 #![forbid(unsafe_code)]
-#![deny(private_in_public)]
+#![deny(private_interfaces, private_bounds)]
+
+#[macro_use]
+mod macros;
 
 mod backtracking_iter;
+mod batch;
 mod board;
+mod board_iter;
+mod candidate_grid;
+mod conflict;
+mod consecutive_marks;
+mod constraints;
+mod difficulty;
 mod error;
 mod field;
+mod file;
+mod grade;
+mod hint;
+mod inequalities;
+#[cfg(feature = "json")]
+mod json;
 mod position;
 mod position_iter;
+mod rng;
+mod samurai;
+mod solution_counter;
+mod solve_stats;
+mod solver_state;
+mod symmetry;
+mod unit;
 
 // Public API
 pub use {
+    backtracking_iter::BacktrackingIter,
+    batch::solve_many,
     board::Board,
-    error::{SudokuParseError, SudokuSolveError},
+    board_iter::BoardIter,
+    candidate_grid::CandidateGrid,
+    conflict::{Conflict, ConflictKind},
+    consecutive_marks::ConsecutiveMarks,
+    constraints::Constraints,
+    difficulty::Difficulty,
+    error::{
+        BoardFileError, FieldParseError, SudokuConstraintError, SudokuGenerateError,
+        SudokuParseError, SudokuSolveError, SudokuTransformError,
+    },
     field::Field,
+    grade::Grade,
+    hint::{Hint, Technique},
+    inequalities::Inequalities,
     position::Position,
     position_iter::PositionIter,
+    samurai::Samurai,
+    solve_stats::{SolveStats, StatsIter},
+    solver_state::{SolverState, StepEvent},
+    symmetry::Symmetry,
 };
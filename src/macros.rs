@@ -0,0 +1,61 @@
+/// Create a `Board` from a string literal, panicking with the parse errors marked on
+/// the input grid if the literal is not a valid sudoku. Handy in tests and examples,
+/// where an invalid board is a bug.
+///
+/// ```
+/// use fabrik::board;
+///
+/// let board = board!(
+///     "6-------4
+///      -42-3-51-
+///      -85---32-
+///      ---3-5---
+///      53--4--68
+///      ---6-2---
+///      -26-5-89-
+///      -97---45-
+///      1-------2"
+/// );
+///
+/// assert!(board.has_unique_solution());
+/// ```
+#[macro_export]
+macro_rules! board {
+    ($input:expr) => {{
+        let input: &str = $input;
+
+        match $crate::Board::try_from(input) {
+            Ok(board) => board,
+            Err(error) => panic!("Invalid board: {}\n{}", error, error.render_on(input)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn creates_a_board_from_a_literal() {
+        let board = board!(include_str!("../sudokus/starry.txt"));
+
+        assert_eq!(
+            Ok(board),
+            crate::Board::try_from(include_str!("../sudokus/starry.txt"))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid board: Sudoku has invalid fields")]
+    fn panics_on_an_invalid_literal() {
+        board!(
+            "66-------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------
+             ---------"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 /// A position on the sudoku board
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Position {
@@ -8,27 +10,87 @@ pub struct Position {
 }
 
 impl Position {
-    /// Create a position from a 0-based field index of the whole board
-    pub(crate) fn from_index(index: usize) -> Position {
+    /// Create a position from a 0-based field index of the whole board in row-major
+    /// order. Returns `None` if the index is outside the board, i.e. 81 or above.
+    pub fn from_index(index: usize) -> Option<Position> {
+        // Last index on board is 80
+        if index < 81 {
+            Some(Position {
+                row: index / 9,
+                column: index % 9,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Get the 0-based field index of the position in row-major order, 0-80
+    pub fn to_index(&self) -> usize {
+        self.row * 9 + self.column
+    }
+
+    /// Get the index of the 3x3 box containing the position, 0-8 in reading order
+    pub fn box_index(&self) -> usize {
+        self.row / 3 * 3 + self.column / 3
+    }
+
+    /// Get the top-left position of the 3x3 box containing the position
+    pub fn box_origin(&self) -> Position {
         Position {
-            row: index / 9,
-            column: index % 9,
+            row: self.row / 3 * 3,
+            column: self.column / 3 * 3,
         }
     }
 
     /// Create a new position based on the current one, but incremented one field
     pub(crate) fn incremented_copy(&self) -> Option<Position> {
-        let coords = self.row * 9 + self.column + 1;
+        Position::from_index(self.to_index() + 1)
+    }
 
-        // Last coord on board is 80
-        if coords < 81 {
-            Some(Position {
-                row: coords / 9,
-                column: coords % 9,
+    /// Do the two positions share a side, i.e. are they next to each other in a row or
+    /// a column?
+    pub(crate) fn is_adjacent_to(&self, other: Position) -> bool {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column) == 1
+    }
+
+    /// Iterate the positions a chess knight's move away, which are fewer near the edges
+    pub(crate) fn knight_moves(&self) -> impl Iterator<Item = Position> {
+        self.offsets([
+            (-2, -1),
+            (-2, 1),
+            (-1, -2),
+            (-1, 2),
+            (1, -2),
+            (1, 2),
+            (2, -1),
+            (2, 1),
+        ])
+    }
+
+    /// Iterate the positions touching the corners of this position, which are fewer
+    /// near the edges
+    pub(crate) fn diagonal_neighbours(&self) -> impl Iterator<Item = Position> {
+        self.offsets([(-1, -1), (-1, 1), (1, -1), (1, 1)])
+    }
+
+    /// Iterate the positions at row and column offsets from this position, skipping the
+    /// ones outside the board
+    fn offsets<const N: usize>(
+        &self,
+        offsets: [(isize, isize); N],
+    ) -> impl Iterator<Item = Position> {
+        let Position { row, column } = *self;
+
+        offsets
+            .into_iter()
+            .filter_map(move |(row_offset, column_offset)| {
+                Some(Position {
+                    row: row.checked_add_signed(row_offset).filter(|row| *row < 9)?,
+                    column: column
+                        .checked_add_signed(column_offset)
+                        .filter(|column| *column < 9)?,
+                })
             })
-        } else {
-            None
-        }
     }
 
     /// Get the row
@@ -41,3 +103,100 @@ impl Position {
         self.column
     }
 }
+
+/// Render a `Position` for humans, counting from 1, e.g. "row 3, column 5"
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "row {}, column {}", self.row + 1, self.column + 1)
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn knight_moves_stay_on_the_board() {
+        assert_eq!(Position { row: 4, column: 4 }.knight_moves().count(), 8);
+        assert_eq!(
+            Position { row: 0, column: 0 }
+                .knight_moves()
+                .collect::<Vec<Position>>(),
+            vec![
+                Position { row: 1, column: 2 },
+                Position { row: 2, column: 1 }
+            ]
+        );
+    }
+
+    #[test]
+    fn diagonal_neighbours_stay_on_the_board() {
+        assert_eq!(
+            Position { row: 4, column: 4 }.diagonal_neighbours().count(),
+            4
+        );
+        assert_eq!(
+            Position { row: 8, column: 0 }
+                .diagonal_neighbours()
+                .collect::<Vec<Position>>(),
+            vec![Position { row: 7, column: 1 }]
+        );
+    }
+
+    #[test]
+    fn converts_to_and_from_index() {
+        assert_eq!(
+            Position::from_index(0),
+            Some(Position { row: 0, column: 0 })
+        );
+        assert_eq!(
+            Position::from_index(40),
+            Some(Position { row: 4, column: 4 })
+        );
+        assert_eq!(
+            Position::from_index(80),
+            Some(Position { row: 8, column: 8 })
+        );
+        assert_eq!(Position::from_index(81), None);
+
+        for index in 0..81 {
+            assert_eq!(Position::from_index(index).unwrap().to_index(), index);
+        }
+    }
+
+    #[test]
+    fn finds_the_box_of_a_position() {
+        let position = Position { row: 4, column: 7 };
+
+        assert_eq!(position.box_index(), 5);
+        assert_eq!(position.box_origin(), Position { row: 3, column: 6 });
+
+        assert_eq!(Position { row: 0, column: 0 }.box_index(), 0);
+        assert_eq!(
+            Position { row: 8, column: 8 }.box_origin(),
+            Position { row: 6, column: 6 }
+        );
+    }
+
+    #[test]
+    fn displays_the_position_counting_from_one() {
+        assert_eq!(
+            Position { row: 0, column: 0 }.to_string(),
+            "row 1, column 1"
+        );
+        assert_eq!(
+            Position { row: 2, column: 4 }.to_string(),
+            "row 3, column 5"
+        );
+    }
+
+    #[test]
+    fn incrementing_past_the_last_field_ends_the_board() {
+        assert_eq!(
+            Position { row: 0, column: 8 }.incremented_copy(),
+            Some(Position { row: 1, column: 0 })
+        );
+        assert_eq!(Position { row: 8, column: 8 }.incremented_copy(), None);
+    }
+}
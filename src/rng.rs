@@ -0,0 +1,77 @@
+/// A small seeded pseudo random number generator using the SplitMix64 algorithm.
+///
+/// It is not suitable for cryptography, but fast and good enough for shuffling, and
+/// makes every randomized result reproducible from its seed without depending on `rand`.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Create a generator from a seed. The same seed always gives the same numbers.
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Get the next random number
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut random = self.state;
+        random = (random ^ (random >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        random = (random ^ (random >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        random ^ (random >> 31)
+    }
+
+    /// Get a random number from 0 up to, but not including, `bound`. The bound must not be 0.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Shuffle a slice in place using the Fisher-Yates algorithm
+pub(crate) fn shuffle<T>(items: &mut [T], rng: &mut Rng) {
+    for index in (1..items.len()).rev() {
+        items.swap(index, rng.below(index + 1));
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_gives_the_same_numbers() {
+        let mut rng = Rng::new(42);
+        let mut same_seed = Rng::new(42);
+        let mut other_seed = Rng::new(43);
+
+        let numbers = (0..10).map(|_| rng.next_u64()).collect::<Vec<u64>>();
+
+        assert_eq!(
+            numbers,
+            (0..10).map(|_| same_seed.next_u64()).collect::<Vec<u64>>()
+        );
+        assert_ne!(
+            numbers,
+            (0..10).map(|_| other_seed.next_u64()).collect::<Vec<u64>>()
+        );
+    }
+
+    #[test]
+    fn shuffles_into_a_permutation() {
+        let mut items = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        shuffle(&mut items, &mut Rng::new(7));
+
+        assert_ne!(items, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut sorted = items;
+        sorted.sort_unstable();
+        assert_eq!(sorted, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut same_seed = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        shuffle(&mut same_seed, &mut Rng::new(7));
+        assert_eq!(same_seed, items);
+    }
+}
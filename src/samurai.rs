@@ -0,0 +1,338 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{SudokuParseError, SudokuSolveError},
+    field::Field,
+    position::Position,
+    Board,
+};
+
+/// Number of rows and columns in the cross shape of a Samurai sudoku
+const SIZE: usize = 21;
+
+/// The row and column of the top-left field of each grid within the cross shape, in the
+/// order top-left, top-right, center, bottom-left, bottom-right
+const GRID_OFFSETS: [(usize, usize); 5] = [(0, 0), (0, 12), (6, 6), (12, 0), (12, 12)];
+
+/// A Samurai sudoku, which is five sudoku grids overlapping in the shape of a cross.
+/// The center grid shares each of its four corner boxes with one of the outer grids,
+/// so the digits in a shared box must satisfy the rules of both grids.
+///
+/// A Samurai sudoku is parsed from 21 lines of 21 characters, where the fields of the
+/// grids use the same characters as a `Board`, and everything outside the grids is ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Samurai {
+    grids: [Board; 5],
+}
+
+impl Samurai {
+    /// Get the five grids in the order top-left, top-right, center, bottom-left,
+    /// bottom-right
+    pub fn grids(&self) -> &[Board; 5] {
+        &self.grids
+    }
+
+    /// Get the first solution for the Samurai sudoku.
+    ///
+    /// The fields of all five grids are searched as one puzzle, where a field in a shared
+    /// box must fit the rows, columns and boxes of both grids it belongs to. The search
+    /// backtracks over the empty field with the fewest candidates, so constraints from
+    /// the outer grids prune the center grid and the other way around.
+    ///
+    /// Fails with `Contradiction` if an empty field of a grid has no candidates on its
+    /// own, where the position is relative to that grid, and with `Exhausted` if the
+    /// grids disagree on a shared field or the search finds no solution.
+    pub fn solve(self) -> Result<Samurai, SudokuSolveError> {
+        if let Some(position) = self.grids.iter().find_map(Board::find_contradiction) {
+            return Err(SudokuSolveError::Contradiction { position });
+        }
+
+        let cells = cells();
+        let mut grids = self.grids;
+
+        // Copy the fields of shared boxes to the grid where they are empty, so both
+        // grids start out with the same fields
+        for cell in &cells {
+            let mut fields = cell
+                .iter()
+                .map(|(grid, position)| *grids[*grid].get_field(*position))
+                .filter(Field::is_filled);
+
+            let Some(field) = fields.next() else {
+                continue;
+            };
+
+            if fields.any(|other| other != field) {
+                return Err(SudokuSolveError::Exhausted);
+            }
+
+            for (grid, position) in cell {
+                if grids[*grid].is_empty_at(*position) {
+                    if !grids[*grid].valid_number_at_position(*position, &field) {
+                        return Err(SudokuSolveError::Exhausted);
+                    }
+
+                    grids[*grid].put_field(*position, field);
+                }
+            }
+        }
+
+        if search(&mut grids, &cells) {
+            Ok(Samurai { grids })
+        } else {
+            Err(SudokuSolveError::Exhausted)
+        }
+    }
+
+    /// Get the `Field` at a row and column of the cross shape, or `None` if the row and
+    /// column is outside the grids
+    fn field_at(&self, row: usize, column: usize) -> Option<&Field> {
+        grid_positions(row, column)
+            .first()
+            .map(|(grid, position)| self.grids[*grid].get_field(*position))
+    }
+}
+
+/// A field of the cross shape, given as the grid index and `Position` of the field in
+/// every grid it belongs to. Fields in shared boxes belong to two grids.
+type Cell = Vec<(usize, Position)>;
+
+/// Get every field of the cross shape, with each shared field listed once
+fn cells() -> Vec<Cell> {
+    (0..SIZE)
+        .flat_map(|row| (0..SIZE).map(move |column| grid_positions(row, column)))
+        .filter(|cell| !cell.is_empty())
+        .collect()
+}
+
+/// Get the grid index and `Position` of a row and column of the cross shape in every
+/// grid containing it, which is empty if the row and column is outside the grids
+fn grid_positions(row: usize, column: usize) -> Cell {
+    GRID_OFFSETS
+        .iter()
+        .enumerate()
+        .filter_map(|(grid, (row_offset, column_offset))| {
+            let position = Position {
+                row: row.checked_sub(*row_offset).filter(|row| *row < 9)?,
+                column: column
+                    .checked_sub(*column_offset)
+                    .filter(|column| *column < 9)?,
+            };
+
+            Some((grid, position))
+        })
+        .collect()
+}
+
+/// Get the digits that fit a cell in every grid it belongs to
+fn candidates(grids: &[Board; 5], cell: &Cell) -> Vec<Field> {
+    Field::all()
+        .into_iter()
+        .filter(|field| {
+            cell.iter()
+                .all(|(grid, position)| grids[*grid].valid_number_at_position(*position, field))
+        })
+        .collect()
+}
+
+/// Fill the empty cells of the grids by backtracking, always working on the empty cell
+/// with the fewest candidates. Returns false if there is no solution, in which case the
+/// grids are left as they were.
+fn search(grids: &mut [Board; 5], cells: &[Cell]) -> bool {
+    let next = cells
+        .iter()
+        .filter(|cell| {
+            let (grid, position) = cell[0];
+            grids[grid].is_empty_at(position)
+        })
+        .map(|cell| (cell, candidates(grids, cell)))
+        .min_by_key(|(_, candidates)| candidates.len());
+
+    let Some((cell, candidates)) = next else {
+        return true;
+    };
+
+    for field in candidates {
+        for (grid, position) in cell {
+            grids[*grid].put_field(*position, field);
+        }
+
+        if search(grids, cells) {
+            return true;
+        }
+    }
+
+    for (grid, position) in cell {
+        grids[*grid].put_field(*position, Field::empty());
+    }
+
+    false
+}
+
+/// Create a `Samurai` from 21 lines in the shape of a cross. Lines may be shorter than
+/// 21 characters, in which case the missing fields are empty. Fails with `InvalidLength`
+/// if there are not 21 lines, or with the parse errors of the first invalid grid, where
+/// the positions are relative to that grid.
+impl TryFrom<&str> for Samurai {
+    type Error = SudokuParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let lines = input
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+
+        if lines.len() != SIZE {
+            return Err(SudokuParseError::InvalidLength);
+        }
+
+        let [top_left, top_right, center, bottom_left, bottom_right] =
+            GRID_OFFSETS.map(|(row_offset, column_offset)| {
+                let mut fields = Vec::with_capacity(81);
+
+                for line in &lines[row_offset..row_offset + 9] {
+                    for column in column_offset..column_offset + 9 {
+                        let character = line.get(column).copied().unwrap_or(' ');
+
+                        // Use an invalid field value for invalid characters, which will
+                        // fail when parsing the grid
+                        fields
+                            .push(Field::from_char(character).map_or(Some(u8::MAX), |f| f.value()));
+                    }
+                }
+
+                Board::try_from(fields)
+            });
+
+        Ok(Samurai {
+            grids: [top_left?, top_right?, center?, bottom_left?, bottom_right?],
+        })
+    }
+}
+
+/// Render the `Samurai` in the cross shape it is parsed from, with `-` for empty fields
+impl Display for Samurai {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..SIZE {
+            let line = (0..SIZE)
+                .map(|column| match self.field_at(row, column) {
+                    Some(field) if field.is_empty() => '-',
+                    Some(field) => field.to_char(),
+                    None => ' ',
+                })
+                .collect::<String>();
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position_iter::PositionIter;
+
+    /// Index of the center grid
+    const CENTER: usize = 2;
+
+    /// The boxes shared between an outer grid and the center grid, as the outer grid index,
+    /// the origin of the box in the outer grid and the origin of the box in the center grid
+    const SHARED_BOXES: [(usize, Position, Position); 4] = [
+        (
+            0,
+            Position { row: 6, column: 6 },
+            Position { row: 0, column: 0 },
+        ),
+        (
+            1,
+            Position { row: 6, column: 0 },
+            Position { row: 0, column: 6 },
+        ),
+        (
+            3,
+            Position { row: 0, column: 6 },
+            Position { row: 6, column: 0 },
+        ),
+        (
+            4,
+            Position { row: 0, column: 0 },
+            Position { row: 6, column: 6 },
+        ),
+    ];
+
+    #[test]
+    fn parses_and_displays_the_cross_shape() {
+        let input = include_str!("../sudokus/samurai.txt");
+        let samurai = Samurai::try_from(input).unwrap();
+
+        assert_eq!(samurai.to_string(), input);
+        assert_eq!(
+            samurai.grids()[1].get_field(Position { row: 0, column: 0 }),
+            &Field::from_u8(9)
+        );
+
+        assert_eq!(
+            Samurai::try_from("1-------").err(),
+            Some(SudokuParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn solves_all_grids_consistently() {
+        let samurai = Samurai::try_from(include_str!("../sudokus/samurai.txt")).unwrap();
+        assert_consistent_solution(&samurai, &samurai.solve().unwrap());
+    }
+
+    #[test]
+    fn solves_a_center_grid_constrained_by_the_outer_grids() {
+        // The "sudokus/samurai.txt" puzzle with only the shared corner boxes left in the
+        // center grid, which has a huge number of solutions on its own
+        let samurai =
+            Samurai::try_from(include_str!("../sudokus/samurai_open_center.txt")).unwrap();
+        assert!(samurai.grids()[CENTER].count_solutions(Some(1_000), None) >= 1_000);
+
+        assert_consistent_solution(&samurai, &samurai.solve().unwrap());
+    }
+
+    // Is the solution complete and valid, with the givens of the samurai and agreeing
+    // overlapping fields?
+    fn assert_consistent_solution(samurai: &Samurai, solution: &Samurai) {
+        for (grid, solved_grid) in samurai.grids().iter().zip(solution.grids()) {
+            assert!(solved_grid.empty_positions().next().is_none());
+            assert!(solved_grid.validate().is_ok());
+
+            for position in PositionIter::from_first_field() {
+                if grid.is_filled_at(position) {
+                    assert_eq!(grid.get_field(position), solved_grid.get_field(position));
+                }
+            }
+        }
+
+        // Overlapping fields agree, so the solution renders without contradictions
+        let rendered = solution.to_string();
+        assert_eq!(&Samurai::try_from(rendered.as_str()).unwrap(), solution);
+        assert!(!rendered.contains('-'));
+        assert_eq!(
+            SHARED_BOXES
+                .iter()
+                .filter(|(outer, outer_origin, center_origin)| {
+                    (0..3).all(|row| {
+                        (0..3).all(|column| {
+                            solution.grids()[*outer].get_field(Position {
+                                row: outer_origin.row + row,
+                                column: outer_origin.column + column,
+                            }) == solution.grids()[CENTER].get_field(Position {
+                                row: center_origin.row + row,
+                                column: center_origin.column + column,
+                            })
+                        })
+                    })
+                })
+                .count(),
+            4
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{field::Field, position::Position, position_iter::PositionIter, Board};
+
+/// Backtracking search made for counting solutions.
+///
+/// It visits the same boards in the same order as `BacktrackingIter`, so limits on
+/// iterations mean the same, but it never emits a board. Instead of scanning the row,
+/// column and box of a field for every digit tried, it keeps a bitmask of the digits
+/// used in every row, column and box.
+#[derive(Clone, Debug)]
+pub(crate) struct SolutionCounter {
+    board: Board,
+    /// The empty fields of the board in row-major order
+    empty: Vec<Position>,
+    /// Bitmasks of used digits, where bit n is the digit n
+    rows: [u16; 9],
+    columns: [u16; 9],
+    boxes: [u16; 9],
+}
+
+impl SolutionCounter {
+    /// Create a counter for the solutions of a `Board`
+    pub(crate) fn new(board: Board) -> Self {
+        let mut counter = SolutionCounter {
+            board,
+            empty: board.empty_positions().collect(),
+            rows: [0; 9],
+            columns: [0; 9],
+            boxes: [0; 9],
+        };
+
+        for position in PositionIter::from_first_field() {
+            if let Some(digit) = board.get_field(position).value() {
+                counter.toggle(position, digit);
+            }
+        }
+
+        counter
+    }
+
+    /// Count the solutions while collecting the first solutions found, up to
+    /// `collect_up_to`. Stops after `max_solutions` solutions or `max_iterations`
+    /// placements, where a placement is a board emitted by `BacktrackingIter`.
+    pub(crate) fn count(
+        mut self,
+        collect_up_to: usize,
+        max_solutions: Option<usize>,
+        max_iterations: Option<usize>,
+    ) -> (usize, Vec<Board>) {
+        let max_solutions = max_solutions.unwrap_or(usize::MAX);
+        let max_iterations = max_iterations.unwrap_or(usize::MAX);
+
+        let mut solutions = Vec::new();
+        let mut count = 0;
+        let mut iterations = 0;
+
+        // The digit placed in each empty field, where 0 is none yet
+        let mut digits = vec![0; self.empty.len()];
+        let mut depth = 0;
+
+        if self.empty.is_empty() {
+            return (count, solutions);
+        }
+
+        while iterations < max_iterations && count < max_solutions {
+            let position = self.empty[depth];
+
+            if digits[depth] != 0 {
+                self.toggle(position, digits[depth]);
+            }
+
+            let used = self.used(position);
+
+            match (digits[depth] + 1..=9).find(|digit| used & 1 << digit == 0) {
+                Some(digit) => {
+                    self.toggle(position, digit);
+                    digits[depth] = digit;
+                    iterations += 1;
+
+                    if depth + 1 < self.empty.len() {
+                        depth += 1;
+                    } else {
+                        count += 1;
+
+                        if solutions.len() < collect_up_to {
+                            solutions.push(self.solution(&digits));
+                        }
+                    }
+                }
+                None => {
+                    // All digits were tried for this field, so backtrack to the previous
+                    digits[depth] = 0;
+
+                    if depth == 0 {
+                        break;
+                    }
+
+                    depth -= 1;
+                }
+            }
+        }
+
+        (count, solutions)
+    }
+
+    /// Get the digits used in the row, column and box of a position
+    fn used(&self, position: Position) -> u16 {
+        self.rows[position.row] | self.columns[position.column] | self.boxes[position.box_index()]
+    }
+
+    /// Mark a digit as used at a position if it is not, and as unused if it is
+    fn toggle(&mut self, position: Position, digit: u8) {
+        let bit = 1 << digit;
+
+        self.rows[position.row] ^= bit;
+        self.columns[position.column] ^= bit;
+        self.boxes[position.box_index()] ^= bit;
+    }
+
+    /// Get the board with the digits put in the empty fields
+    fn solution(&self, digits: &[u8]) -> Board {
+        let mut board = self.board;
+
+        for (position, digit) in self.empty.iter().zip(digits) {
+            board.put_field(*position, Field::from_u8(*digit));
+        }
+
+        board
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::Display;
+
+use crate::{
+    field::Field,
+    solver_state::{SolverState, StepEvent},
+    Board,
+};
+
+/// Cumulative statistics of a backtracking search
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolveStats {
+    iterations: usize,
+    guesses: usize,
+    backtracks: usize,
+}
+
+impl SolveStats {
+    /// Get the number of boards emitted so far, which is the number of fields put on
+    /// the board
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Get the number of fields put on the board while other digits were also possible
+    /// for the field
+    pub fn guesses(&self) -> usize {
+        self.guesses
+    }
+
+    /// Get the number of fields cleared again when backtracking
+    pub fn backtracks(&self) -> usize {
+        self.backtracks
+    }
+}
+
+/// Render the `SolveStats` for logging, e.g. "iterations=228 guesses=12 backtracks=4"
+impl Display for SolveStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "iterations={} guesses={} backtracks={}",
+            self.iterations, self.guesses, self.backtracks
+        )
+    }
+}
+
+/// Iterator emitting the same boards as `BacktrackingIter` together with the
+/// `SolveStats` of the search so far. Created with `BacktrackingIter::with_stats`.
+#[derive(Clone, Debug)]
+pub struct StatsIter {
+    state: SolverState,
+    stats: SolveStats,
+}
+
+impl StatsIter {
+    /// Create a stats iterator continuing the search of a `SolverState`
+    pub(crate) fn new(state: SolverState) -> Self {
+        StatsIter {
+            state,
+            stats: SolveStats::default(),
+        }
+    }
+}
+
+impl Iterator for StatsIter {
+    type Item = (Board, bool, SolveStats);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state.step()? {
+                StepEvent::Placed { position, .. } => {
+                    let mut board_before = *self.state.board();
+                    board_before.put_field(position, Field::empty());
+
+                    self.stats.iterations += 1;
+
+                    if board_before.candidates(position).len() > 1 {
+                        self.stats.guesses += 1;
+                    }
+
+                    return Some((*self.state.board(), self.state.is_solved(), self.stats));
+                }
+                StepEvent::Cleared { .. } => self.stats.backtracks += 1,
+            }
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emits_the_same_boards_with_cumulative_stats() {
+        let board = Board::try_from(include_str!("../sudokus/starry.txt")).unwrap();
+        let mut previous = SolveStats::default();
+
+        for ((stats_board, stats_solved, stats), (plain_board, plain_solved)) in
+            board.solve_iter().with_stats().zip(board.solve_iter())
+        {
+            assert_eq!(stats_board, plain_board);
+            assert_eq!(stats_solved, plain_solved);
+
+            assert_eq!(stats.iterations(), previous.iterations() + 1);
+            assert!(stats.guesses() >= previous.guesses());
+            assert!(stats.backtracks() >= previous.backtracks());
+            assert!(stats.guesses() <= stats.iterations());
+            assert!(stats.backtracks() <= stats.iterations());
+
+            previous = stats;
+        }
+
+        assert_eq!(previous.iterations(), board.solve_iter().count());
+        assert!(previous.guesses() > 0);
+        assert!(previous.backtracks() > 0);
+    }
+
+    #[test]
+    fn displays_for_logging() {
+        let stats = SolveStats {
+            iterations: 228,
+            guesses: 12,
+            backtracks: 4,
+        };
+
+        assert_eq!(stats.to_string(), "iterations=228 guesses=12 backtracks=4");
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn serializes_the_counts() {
+        let stats = SolveStats {
+            iterations: 228,
+            guesses: 12,
+            backtracks: 4,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&stats).unwrap(),
+            r#"{"iterations":228,"guesses":12,"backtracks":4}"#
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use crate::{constraints::Constraints, unit::Unit, Board};
+
+use super::{field::Field, position::Position};
+
+/// Step-by-step backtracking solver emitting a `StepEvent` for every single change
+/// made to the board.
+///
+/// Unlike `BacktrackingIter`, which emits a copy of the whole board after every
+/// placement, the `SolverState` only describes what changed. This lets renderers
+/// update a single field at a time. The current board can be inspected with `board`.
+#[derive(Clone, Debug)]
+pub struct SolverState {
+    board: Board,
+    stack: Vec<WorkOnField>,
+    is_solved: bool,
+    order: [u8; 9],
+    forward_checking: bool,
+    constraints: Constraints,
+}
+
+/// A single change made to the board by the `SolverState`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepEvent {
+    /// A `Field` was put on the board at a `Position`
+    Placed {
+        /// The position of the field
+        position: Position,
+        /// The field that was put on the board
+        field: Field,
+    },
+    /// A field was cleared when backtracking from a `Position`
+    Cleared {
+        /// The position of the cleared field
+        position: Position,
+    },
+}
+
+/// Work on the field at a position, trying the digit at an index in the digit order next
+#[derive(Copy, Clone, Debug)]
+struct WorkOnField(Position, usize);
+
+impl SolverState {
+    /// Create a solver state for a Board
+    pub fn new(board: Board) -> Self {
+        SolverState::starting_at(board, Position { row: 0, column: 0 })
+    }
+
+    /// Create a solver state for a Board that only works on the empty fields from
+    /// a `Position` and onwards
+    pub(crate) fn starting_at(board: Board, start: Position) -> Self {
+        let mut state = SolverState {
+            board,
+            stack: Vec::new(),
+            is_solved: false,
+            order: [1, 2, 3, 4, 5, 6, 7, 8, 9],
+            forward_checking: false,
+            constraints: Constraints::new(),
+        };
+
+        state.prepare_stack(start);
+        state
+    }
+
+    /// Try the digits in the given order instead of ascending. The order must be a
+    /// permutation of 1-9.
+    pub(crate) fn with_order(mut self, order: [u8; 9]) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Never change the fields at the locked positions. The solver never clears filled
+    /// fields, so only locked empty fields need handling. They can never be filled, so
+    /// there is nothing to search.
+    pub(crate) fn with_locked(mut self, locked: &HashSet<Position>) -> Self {
+        if locked
+            .iter()
+            .any(|position| self.board.get_field(*position).is_empty())
+        {
+            self.stack.clear();
+        }
+
+        self
+    }
+
+    /// Only place a digit if every empty field sharing a row, column or box with it still
+    /// has a candidate afterwards. This prunes dead ends early, without changing the
+    /// solutions found.
+    pub(crate) fn with_forward_checking(mut self) -> Self {
+        self.forward_checking = true;
+        self
+    }
+
+    /// Respect extra constraints of a variant sudoku when placing digits. If the filled
+    /// fields already break a constraint, there is nothing to search.
+    pub(crate) fn with_constraints(mut self, constraints: Constraints) -> Self {
+        if !constraints.are_satisfied_by(&self.board) {
+            self.stack.clear();
+        }
+
+        self.constraints = constraints;
+        self
+    }
+
+    /// Get the board as it looks after the latest step
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Did the latest step solve the board?
+    pub fn is_solved(&self) -> bool {
+        self.is_solved
+    }
+
+    /// Get the depth of the search, which is the number of fields currently filled in by
+    /// the solver and which it may backtrack to
+    pub fn depth(&self) -> usize {
+        // The stack holds an instruction for every field filled in, and possibly one more
+        // for the next empty field to work on
+        let next_field_is_prepared = matches!(
+            self.stack.last(),
+            Some(WorkOnField(position, _)) if self.board.get_field(*position).is_empty()
+        );
+
+        self.stack.len() - usize::from(next_field_is_prepared)
+    }
+
+    /// Take a single step towards a solution. Returns `None` when all possibilities
+    /// have been tried for the board.
+    pub fn step(&mut self) -> Option<StepEvent> {
+        loop {
+            let WorkOnField(pos, next_index) = self.stack.pop()?;
+
+            for index in next_index..9 {
+                let field = Field::from_u8(self.order[index]);
+
+                if self.board.valid_number_at_position(pos, &field)
+                    && self.constraints.allows(&self.board, pos, field)
+                    && (!self.forward_checking || self.keeps_peers_solvable(pos, field))
+                {
+                    // Insert WorkOnField(pos, index + 1) on the top of the stack,
+                    // to be able to resume work on this field if we backtrack to this position again.
+                    self.stack.push(WorkOnField(pos, index + 1));
+                    self.board.put_field(pos, field);
+
+                    // If there's a next empty field, prepare the stack for that field. If not,
+                    // then we consider the board solved.
+                    self.is_solved = !self.prepare_stack(pos);
+
+                    return Some(StepEvent::Placed {
+                        position: pos,
+                        field,
+                    });
+                }
+            }
+
+            // We have tried all digits for this field. Clear it and loop again,
+            // effectively backtracking to the previous position.
+            self.is_solved = false;
+
+            if self.board.get_field(pos).is_filled() {
+                self.board.put_field(pos, Field::empty());
+                return Some(StepEvent::Cleared { position: pos });
+            }
+        }
+    }
+
+    // Would every empty peer of a position still have a candidate with the field put there?
+    fn keeps_peers_solvable(&self, position: Position, field: Field) -> bool {
+        let mut board = self.board;
+        board.put_field(position, field);
+
+        Unit::containing(position).iter().all(|unit| {
+            unit.positions()
+                .iter()
+                .all(|peer| board.is_filled_at(*peer) || !board.candidates(*peer).is_empty())
+        })
+    }
+
+    // Prepare instructions in the stack for the next empty field from a position.
+    // Returns false if there are no empty fields left.
+    fn prepare_stack(&mut self, position: Position) -> bool {
+        if let Some(next_empty_field) = self.board.next_empty_field(position) {
+            // Try the first digit in the order first. The index will be incremented up until
+            // the last digit during execution. We could have pushed 9 separate instructions
+            // instead, but this performs better.
+            self.stack.push(WorkOnField(next_empty_field, 0));
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn replaying_events_ends_in_the_first_solution() {
+        // The board is "sudokus/starry.txt"
+        let board = Board::try_from(
+            "6-------4
+             -42-3-51-
+             -85---32-
+             ---3-5---
+             53--4--68
+             ---6-2---
+             -26-5-89-
+             -97---45-
+             1-------2",
+        )
+        .unwrap();
+
+        let mut state = SolverState::new(board);
+        let mut replayed = board;
+
+        while let Some(event) = state.step() {
+            match event {
+                StepEvent::Placed { position, field } => replayed.put_field(position, field),
+                StepEvent::Cleared { position } => replayed.put_field(position, Field::empty()),
+            }
+
+            assert_eq!(&replayed, state.board());
+
+            if state.is_solved() {
+                break;
+            }
+        }
+
+        assert_eq!(replayed, board.first_solution().unwrap());
+    }
+}
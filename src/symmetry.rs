@@ -0,0 +1,57 @@
+use crate::position::Position;
+
+/// A symmetry of the pattern of filled fields on a board
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    /// The pattern looks the same when the board is rotated 180 degrees
+    Rotational,
+    /// The top half of the pattern mirrors the bottom half
+    HorizontalMirror,
+    /// The left half of the pattern mirrors the right half
+    VerticalMirror,
+    /// The pattern is mirrored along the diagonal from the top left corner
+    Diagonal,
+    /// The pattern is mirrored along the diagonal from the top right corner
+    AntiDiagonal,
+}
+
+impl Symmetry {
+    /// Get all symmetries
+    pub fn all() -> [Symmetry; 5] {
+        [
+            Symmetry::Rotational,
+            Symmetry::HorizontalMirror,
+            Symmetry::VerticalMirror,
+            Symmetry::Diagonal,
+            Symmetry::AntiDiagonal,
+        ]
+    }
+
+    /// Get the `Position` a `Position` is mapped to by the symmetry
+    pub fn mirror(&self, position: Position) -> Position {
+        let Position { row, column } = position;
+
+        match self {
+            Symmetry::Rotational => Position {
+                row: 8 - row,
+                column: 8 - column,
+            },
+            Symmetry::HorizontalMirror => Position {
+                row: 8 - row,
+                column,
+            },
+            Symmetry::VerticalMirror => Position {
+                row,
+                column: 8 - column,
+            },
+            Symmetry::Diagonal => Position {
+                row: column,
+                column: row,
+            },
+            Symmetry::AntiDiagonal => Position {
+                row: 8 - column,
+                column: 8 - row,
+            },
+        }
+    }
+}
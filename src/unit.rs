@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use crate::position::Position;
+
+/// A unit on the board is a row, a column or a 3x3 box. Each unit holds all nine
+/// digits exactly once in a solved sudoku.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Unit {
+    /// A row, 0-8
+    Row(usize),
+    /// A column, 0-8
+    Column(usize),
+    /// A 3x3 box in reading order, 0-8
+    Box(usize),
+}
+
+impl Unit {
+    /// Iterate all 27 units on the board: rows first, then columns, then boxes
+    pub(crate) fn all() -> impl Iterator<Item = Unit> {
+        (0..9)
+            .map(Unit::Row)
+            .chain((0..9).map(Unit::Column))
+            .chain((0..9).map(Unit::Box))
+    }
+
+    /// Get the row, column and box containing a position
+    pub(crate) fn containing(position: Position) -> [Unit; 3] {
+        [
+            Unit::Row(position.row),
+            Unit::Column(position.column),
+            Unit::Box(position.box_index()),
+        ]
+    }
+
+    /// Get the nine positions in the unit in reading order
+    pub(crate) fn positions(&self) -> [Position; 9] {
+        let mut positions = [Position { row: 0, column: 0 }; 9];
+
+        for (i, position) in positions.iter_mut().enumerate() {
+            *position = match self {
+                Unit::Row(row) => Position {
+                    row: *row,
+                    column: i,
+                },
+                Unit::Column(column) => Position {
+                    row: i,
+                    column: *column,
+                },
+                Unit::Box(index) => Position {
+                    row: index / 3 * 3 + i / 3,
+                    column: index % 3 * 3 + i % 3,
+                },
+            };
+        }
+
+        positions
+    }
+}
+
+/// Render a `Unit` for humans, counting from 1
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unit::Row(row) => write!(f, "row {}", row + 1),
+            Unit::Column(column) => write!(f, "column {}", column + 1),
+            Unit::Box(index) => write!(f, "box {}", index + 1),
+        }
+    }
+}